@@ -0,0 +1,51 @@
+//! Collider generation for a batch of 100 images.
+//!
+//! With the `parallel` feature, which is enabled by default, [`AbstractCollidersBuilder::batch`]
+//! processes the images concurrently and is compared with generating them one after another.
+
+use bevy_collider_gen::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use edges::binary_image::BinaryImage;
+use image::{DynamicImage, Rgba, RgbaImage};
+
+const IMAGES: u32 = 100;
+const SIZE: u32 = 256;
+
+/// Creates builders for images with a differently sized opaque circle each.
+fn builders() -> Vec<AbstractCollidersBuilder<BinaryImage>> {
+    (0..IMAGES)
+        .map(|index| {
+            let radius = 32 + index;
+            let image = RgbaImage::from_fn(SIZE, SIZE, |x, y| {
+                let (dx, dy) = (x.abs_diff(SIZE / 2), y.abs_diff(SIZE / 2));
+                let alpha = if dx * dx + dy * dy < radius * radius {
+                    255
+                } else {
+                    0
+                };
+                Rgba([0, 0, 0, alpha])
+            });
+            EdgesBuilder::new().dynamic_image(&DynamicImage::ImageRgba8(image))
+        })
+        .collect()
+}
+
+fn batch(c: &mut Criterion) {
+    let builders = builders();
+    let mut group = c.benchmark_group("100 images");
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            builders
+                .iter()
+                .map(AbstractCollidersBuilder::multiple)
+                .collect::<Vec<_>>()
+        });
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| AbstractCollidersBuilder::batch(&builders));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, batch);
+criterion_main!(benches);
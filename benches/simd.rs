@@ -0,0 +1,33 @@
+//! Pixel classification of a 2048×2048 image.
+//!
+//! Compare the scalar and SIMD paths by running the benchmark with and without the `simd` feature:
+//!
+//! ```sh
+//! cargo bench --bench simd
+//! cargo bench --bench simd -F simd
+//! ```
+
+use bevy_collider_gen::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use image::{DynamicImage, Rgba, RgbaImage};
+
+const SIZE: u32 = 2048;
+
+/// An image with a grid of opaque circles on a transparent background.
+fn image() -> DynamicImage {
+    DynamicImage::ImageRgba8(RgbaImage::from_fn(SIZE, SIZE, |x, y| {
+        let (dx, dy) = ((x % 64).abs_diff(32), (y % 64).abs_diff(32));
+        let alpha = if dx * dx + dy * dy < 24 * 24 { 255 } else { 0 };
+        Rgba([200, 100, 50, alpha])
+    }))
+}
+
+fn classification(c: &mut Criterion) {
+    let image = image();
+    c.bench_function("classify 2048x2048 alpha", |b| {
+        b.iter(|| EdgesBuilder::new().threshold(127).dynamic_image(&image));
+    });
+}
+
+criterion_group!(benches, classification);
+criterion_main!(benches);
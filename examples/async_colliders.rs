@@ -0,0 +1,168 @@
+#![allow(clippy::needless_pass_by_value)]
+//! Async colliders: Car
+//! Illustrating how to generate colliders on the `AsyncComputeTaskPool`
+//! while showing a loading indicator, so complex sprites don't stall a frame.
+
+use bevy::{
+    asset::LoadState,
+    prelude::*,
+    tasks::{block_on, futures_lite::future, Task},
+};
+use bevy_collider_gen::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// The sprite waiting for its collider
+#[derive(Component)]
+pub struct ColliderTask(Task<Option<AbstractCollider>>);
+
+/// for despawning the indicator once the collider is inserted
+#[derive(Component)]
+pub struct LoadingText;
+
+/// Car: spawns the sprite together with the task generating its
+/// `bevy_rapier2d` `convex_polyline` collider
+fn car_spawn(
+    mut commands: Commands,
+    game_assets: Res<GameAsset>,
+    image_assets: Res<Assets<Image>>,
+) {
+    let sprite_image = image_assets.get(&game_assets.image_handle).unwrap();
+    let task = AbstractCollidersBuilder::try_from(sprite_image)
+        .unwrap()
+        .convex_polyline()
+        .single_async();
+
+    commands.spawn((
+        ColliderTask(task),
+        RigidBody::Dynamic,
+        Sprite {
+            image: game_assets.image_handle.clone(),
+            ..default()
+        },
+    ));
+}
+
+/// Polls the tasks and inserts the colliders once they are generated
+fn collider_insert(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut ColliderTask)>,
+    loading_text: Query<Entity, With<LoadingText>>,
+) {
+    for (entity, mut task) in &mut tasks {
+        let Some(collider) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        let mut entity = commands.entity(entity);
+        entity.remove::<ColliderTask>();
+        if let Some(collider) = collider.and_then(AbstractCollider::to_rapier) {
+            entity.insert(collider);
+        }
+    }
+    if tasks.is_empty() {
+        for text in &loading_text {
+            commands.entity(text).despawn_recursive();
+        }
+    }
+}
+
+pub fn loading_text_spawn(mut commands: Commands, game_assets: Res<GameAsset>) {
+    commands.spawn((
+        LoadingText,
+        Text("loading colliders...".to_string()),
+        TextFont {
+            font: game_assets.font_handle.clone(),
+            font_size: 20.,
+            ..Default::default()
+        },
+        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.),
+            top: Val::Px(20.),
+            ..default()
+        },
+    ));
+}
+
+///
+/// After this, things don't differ in a way related to this crate, it's just some of my
+/// personal boilerplate
+///
+#[derive(States, Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum AppState {
+    #[default]
+    Loading,
+    Running,
+}
+
+#[derive(Resource, Default)]
+pub struct GameAsset {
+    pub font_handle: Handle<Font>,
+    pub image_handle: Handle<Image>,
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins
+                .set(ImagePlugin::default_nearest())
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "async colliders".to_string(),
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .set(AssetPlugin {
+                    file_path: ".".to_string(),
+                    ..default()
+                }),
+            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.),
+            #[cfg(debug_assertions)]
+            RapierDebugRenderPlugin::default(),
+        ))
+        .init_state::<AppState>()
+        .insert_resource(GameAsset::default())
+        .add_systems(Startup, load_assets)
+        .add_systems(
+            OnExit(AppState::Loading),
+            (camera_spawn, car_spawn, loading_text_spawn),
+        )
+        .add_systems(
+            Update,
+            (
+                check_assets.run_if(in_state(AppState::Loading)),
+                collider_insert.run_if(in_state(AppState::Running)),
+            ),
+        )
+        .run();
+}
+
+pub fn check_assets(
+    asset_server: Res<AssetServer>,
+    game_assets: Res<GameAsset>,
+    mut state: ResMut<NextState<AppState>>,
+) {
+    let loaded = [
+        game_assets.image_handle.id().untyped(),
+        game_assets.font_handle.id().untyped(),
+    ]
+    .into_iter()
+    .all(|id| {
+        asset_server
+            .get_load_state(id)
+            .is_some_and(|state| matches!(state, LoadState::Loaded))
+    });
+    if loaded {
+        state.set(AppState::Running);
+    }
+}
+
+pub fn load_assets(asset_server: Res<AssetServer>, mut game_assets: ResMut<GameAsset>) {
+    game_assets.font_handle = asset_server.load("assets/font/NotoSansMono-Bold.ttf");
+    game_assets.image_handle = asset_server.load("assets/sprite/car.png");
+}
+
+pub fn camera_spawn(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
@@ -121,7 +121,8 @@ fn boulders_spawn(
     let builder = AbstractCollidersBuilder::try_from(sprite_image)
         .unwrap()
         .absolute()
-        .convex_polyline();
+        .convex_polyline()
+        .no_sort();
     let polygons = EdgesIter::new(builder.image());
 
     for (polygon, collider) in polygons.zip(builder.multiple().into_iter()) {
@@ -0,0 +1,32 @@
+//! Generates colliders from images in the browser.
+//!
+//! Build the module with [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen) and serve this directory,
+//! `index.html` draws shapes on a canvas and outlines the convex hull generated from the canvas' pixels.
+//!
+//! ```sh
+//! cargo build --release
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/bevy_collider_gen_wasm.wasm
+//! python3 -m http.server
+//! ```
+
+use bevy_collider_gen::prelude::*;
+use wasm_bindgen::prelude::*;
+
+/// Generates the convex hull of the largest object in RGBA8 pixel data, such as a canvas' `ImageData`.
+///
+/// The points are centered at the image's center with y pointing up, as `x, y` pairs.
+/// The result is empty if `rgba` is smaller than `width * height * 4` bytes or the image has no object.
+#[wasm_bindgen]
+#[must_use]
+pub fn convex_hull(width: u32, height: u32, rgba: &[u8]) -> Vec<f32> {
+    AbstractCollidersBuilder::from_rgba8(width, height, rgba)
+        .and_then(|builder| builder.convex_hull().take_only_largest().single())
+        .and_then(|collider| collider.points().cloned())
+        .map(|points| {
+            points
+                .into_iter()
+                .flat_map(|point| [point.x, point.y])
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,30 @@
+//! Runs with `cargo test`, using `wasm-bindgen-test-runner` as the runner for the wasm target.
+
+use bevy_collider_gen_wasm::convex_hull;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn convex_hull_of_rgba8_bytes() {
+    // A 4×4 opaque block in a transparent 6×6 image.
+    let rgba: Vec<u8> = (0..36)
+        .flat_map(|index| {
+            let (x, y) = (index % 6, index / 6);
+            let alpha = if (1..5).contains(&x) && (1..5).contains(&y) {
+                255
+            } else {
+                0
+            };
+            [255, 0, 0, alpha]
+        })
+        .collect();
+    let points = convex_hull(6, 6, &rgba);
+    assert_eq!(points.len(), 8);
+    for corner in points.chunks(2) {
+        assert_eq!([corner[0].abs(), corner[1].abs()], [1.5, 1.5]);
+    }
+}
+
+#[wasm_bindgen_test]
+fn convex_hull_of_short_data() {
+    assert!(convex_hull(6, 6, &[0; 4]).is_empty());
+}
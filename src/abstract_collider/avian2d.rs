@@ -1,10 +1,12 @@
 use avian2d::{
     parry::{math::Point, shape::SharedShape},
-    prelude::Collider,
+    prelude::{Collider, Position, Rotation},
 };
+use edges::binary_image::Bit;
+use image::GenericImageView;
 
-use super::AbstractCollider;
-use super::AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline};
+use super::AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline, Trimesh};
+use super::{AbstractCollider, AbstractCollidersBuilder, BackendCollider, CompoundColliderBuilder};
 
 impl AbstractCollider {
     #[must_use]
@@ -13,6 +15,18 @@ impl AbstractCollider {
     }
 }
 
+impl<I: GenericImageView<Pixel = Bit>> AbstractCollidersBuilder<I> {
+    /// Generates a single avian2d collider based on the current builder's settings.
+    ///
+    /// # Returns
+    ///
+    /// The collider, or `None` if no object was found or avian2d can't represent it.
+    #[must_use]
+    pub fn build_avian(&self) -> Option<Collider> {
+        self.build()
+    }
+}
+
 impl From<AbstractCollider> for Option<Collider> {
     fn from(value: AbstractCollider) -> Self {
         match value {
@@ -23,6 +37,114 @@ impl From<AbstractCollider> for Option<Collider> {
             }
             ConvexHull(points) => Collider::convex_hull(points),
             Heightfield(heights, scale) => Some(Collider::heightfield(heights, scale)),
+            Trimesh(vertices, indices) => {
+                (!indices.is_empty()).then(|| Collider::trimesh(vertices, indices))
+            }
+        }
+    }
+}
+
+impl BackendCollider for Collider {
+    fn from_abstract(collider: AbstractCollider) -> Option<Self> {
+        collider.into()
+    }
+}
+
+impl CompoundColliderBuilder {
+    /// Converts the compound into a single compound collider.
+    ///
+    /// Composite shapes can't be nested in a compound, so this fails for `Polyline` and `Trimesh` colliders,
+    /// use [`AbstractCollider::to_convex_partition`] to split them into convex parts first.
+    ///
+    /// # Returns
+    ///
+    /// The compound collider, or `None` if the compound is empty or a collider can't be converted or nested.
+    #[must_use]
+    pub fn to_avian(self) -> Option<Collider> {
+        self.into()
+    }
+}
+
+impl From<CompoundColliderBuilder> for Option<Collider> {
+    fn from(value: CompoundColliderBuilder) -> Self {
+        let shapes = value
+            .colliders()
+            .iter()
+            .map(|(offset, collider)| {
+                collider
+                    .clone()
+                    .to_avian()
+                    .filter(|c| c.shape().as_composite_shape().is_none())
+                    .map(|c| (Position::new(*offset), Rotation::IDENTITY, c))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        (!shapes.is_empty()).then(|| Collider::compound(shapes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec2;
+    use edges::{anchor::Anchor, binary_image::BinaryImage};
+
+    use super::*;
+    use crate::abstract_collider::compound::tests::{composite_compounds, convex_compound};
+
+    /// Creates a builder for an 8×6 image with a 4×2 block in the middle.
+    fn builder() -> AbstractCollidersBuilder<BinaryImage> {
+        let cells: Vec<u8> = [
+            "........", "........", "..####..", "..####..", "........", "........",
+        ]
+        .iter()
+        .flat_map(|row| row.bytes())
+        .collect();
+        AbstractCollidersBuilder::from_int_grid(&cells, 8, 6, b"#")
+            .unwrap()
+            .convex_polyline()
+    }
+
+    /// Returns the points of the avian2d collider built from the builder.
+    fn points(builder: &AbstractCollidersBuilder<BinaryImage>) -> Vec<Vec2> {
+        let collider = builder.build_avian().unwrap();
+        collider
+            .shape()
+            .as_convex_polygon()
+            .unwrap()
+            .points()
+            .iter()
+            .map(|point| Vec2::new(point.x, point.y))
+            .collect()
+    }
+
+    #[test]
+    fn compound_of_convex_colliders() {
+        assert!(convex_compound().to_avian().is_some());
+    }
+
+    #[test]
+    fn compound_rejects_composite_colliders() {
+        for compound in composite_compounds() {
+            assert!(compound.to_avian().is_none());
+        }
+    }
+
+    #[test]
+    fn top_left_anchor_keeps_image_coordinates() {
+        // With a zero size the origin is the image's top-left corner, and y points up.
+        let points = points(&builder().with_anchor(Anchor::Center(0, 0)));
+        assert_eq!(points.len(), 4);
+        assert!(points.iter().all(|point| point.x >= 0. && point.y <= 0.));
+        assert!(points.contains(&Vec2::new(2.5, -2.5)));
+        assert!(points.contains(&Vec2::new(5.5, -3.5)));
+    }
+
+    #[test]
+    fn centered_anchor_is_symmetric() {
+        for builder in [builder(), builder().center(8, 6)] {
+            let points = points(&builder);
+            assert_eq!(points.len(), 4);
+            assert!(points.iter().all(|point| points.contains(&-*point)));
+            assert!(points.contains(&Vec2::new(1.5, 0.5)));
         }
     }
 }
@@ -1,8 +1,326 @@
-use bevy::prelude::*;
+use bevy::{image::IntoDynamicImageError, prelude::*};
 use edges::binary_image::{self, BinaryImage, BinaryView};
-use image::DynamicImage;
+use image::{DynamicImage, GenericImageView};
 
 use super::Builder;
+#[cfg(feature = "svg")]
+use crate::svg;
+use crate::{
+    edges_builder::{ColorChannel, EdgesBuilder},
+    geometry,
+    utils::{from_pixels, rasterize},
+};
+
+impl Builder<BinaryImage> {
+    /// Creates a new `Builder` from an integer grid, such as an `LDtk` `IntGrid` layer.
+    ///
+    /// Cells whose value is contained in `solid_values` are treated as opaque,
+    /// all other cells are treated as transparent.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The grid values in row-major order.
+    /// * `width` - The width of the grid in cells.
+    /// * `height` - The height of the grid in cells.
+    /// * `solid_values` - The grid values that should generate colliders.
+    ///
+    /// # Returns
+    ///
+    /// A `Builder` instance, or `None` if the grid is empty or `data` holds fewer than `width * height` values.
+    #[must_use]
+    pub fn from_int_grid(
+        data: &[u8],
+        width: u32,
+        height: u32,
+        solid_values: &[u8],
+    ) -> Option<Self> {
+        let size = width as usize * height as usize;
+        if size == 0 || data.len() < size {
+            return None;
+        }
+        let cells: Vec<u8> = data[..size]
+            .iter()
+            .map(|value| u8::from(solid_values.contains(value)))
+            .collect();
+        Some(Self::new(from_pixels(width, height, &cells)))
+    }
+
+    /// Creates a new `Builder` from a height array, such as a procedurally generated terrain.
+    ///
+    /// This is the inverse of [`image_to_heightfield_heights`](crate::heights::image_to_heightfield_heights),
+    /// `heights[i]` is the height above the bottom of the terrain at `x = i * width / heights.len()`.
+    /// The heights are rasterized into square pixels of `width / heights.len()` world units,
+    /// which the builder's scale maps back into world space.
+    ///
+    /// # Arguments
+    ///
+    /// * `heights` - The heights from left to right, clamped to `0.0..=height`.
+    /// * `width` - The width of the terrain in world units.
+    /// * `height` - The height of the terrain in world units.
+    ///
+    /// # Returns
+    ///
+    /// A `Builder` instance, or `None` if `heights` is empty or `width` or `height` isn't positive and finite.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_world_heightmap(heights: &[f32], width: f32, height: f32) -> Option<Self> {
+        let is_valid = |value: f32| value.is_finite() && value > 0.;
+        if heights.is_empty() || !is_valid(width) || !is_valid(height) {
+            return None;
+        }
+        let pixel_size = width / heights.len() as f32;
+        let rows = (height / pixel_size).ceil() as u32;
+        let columns: Vec<u32> = heights
+            .iter()
+            .map(|column| (column.clamp(0., height) / pixel_size).round() as u32)
+            .collect();
+        let pixels: Vec<u8> = (0..rows)
+            .flat_map(|y| {
+                columns
+                    .iter()
+                    .map(move |column| u8::from(rows - y <= *column))
+            })
+            .collect();
+        let width = u32::try_from(columns.len()).ok()?;
+        Some(Self::new(from_pixels(width, rows, &pixels)).with_scale(Vec2::splat(pixel_size)))
+    }
+
+    /// Creates a new `Builder` from a tilemap, combining the tiles of a whole level into one image.
+    ///
+    /// Every cell of the map is filled with the tile image its id refers to, so the colliders
+    /// of a level can be generated for a single compound body instead of one entity per tile.
+    /// Cells with an id outside of `tile_images` are treated as transparent.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile_images` - The tile images, indexed by tile id, all of the same size.
+    /// * `tile_map` - The tile ids in row-major order.
+    /// * `map_width` - The width of the map in tiles.
+    /// * `map_height` - The height of the map in tiles.
+    ///
+    /// # Returns
+    ///
+    /// A `Builder` instance, or `None` if the map or tiles are empty, `tile_map` holds fewer than
+    /// `map_width * map_height` ids, the tiles differ in size, a tile has an unsupported texture format,
+    /// or the combined image is wider or taller than `u32::MAX` pixels.
+    #[must_use]
+    pub fn from_tilemap(
+        tile_images: &[&Image],
+        tile_map: &[u16],
+        map_width: u32,
+        map_height: u32,
+    ) -> Option<Self> {
+        let size = map_width as usize * map_height as usize;
+        if size == 0 || tile_map.len() < size {
+            return None;
+        }
+        let tiles = tile_images
+            .iter()
+            .map(|image| BinaryImage::try_from(*image).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (tile_width, tile_height) = tiles.first()?.dimensions();
+        if tile_width == 0
+            || tile_height == 0
+            || tiles
+                .iter()
+                .any(|tile| tile.dimensions() != (tile_width, tile_height))
+        {
+            return None;
+        }
+
+        let width = map_width.checked_mul(tile_width)?;
+        let height = map_height.checked_mul(tile_height)?;
+        let pixels: Vec<u8> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let cell = (y / tile_height * map_width + x / tile_width) as usize;
+                tiles.get(usize::from(tile_map[cell])).map_or(0, |tile| {
+                    u8::from(*tile.get_pixel(x % tile_width, y % tile_height))
+                })
+            })
+            .collect();
+        Some(Self::new(from_pixels(width, height, &pixels)))
+    }
+
+    /// Creates a new `Builder` from raw RGBA8 pixel data.
+    ///
+    /// Pixels with a non-zero alpha value are treated as opaque,
+    /// use [`EdgesBuilder`] for other thresholds.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the image in pixels.
+    /// * `height` - The height of the image in pixels.
+    /// * `data` - The pixel data in row-major order, four bytes per pixel.
+    ///
+    /// # Returns
+    ///
+    /// A `Builder` instance, or `None` if `data` is smaller than `width * height * 4` bytes.
+    #[must_use]
+    pub fn from_rgba8(width: u32, height: u32, data: &[u8]) -> Option<Self> {
+        EdgesBuilder::new().rgba8(width, height, data)
+    }
+
+    /// Creates a new `Builder` from a separate collision mask image.
+    ///
+    /// Keeping the physics shape in its own image decouples it from the visual sprite.
+    /// Pixels with a non-zero alpha or luminance value are treated as opaque,
+    /// use [`EdgesBuilder`] for other thresholds.
+    ///
+    /// # Arguments
+    ///
+    /// * `mask` - The mask image, the dimensions of the colliders are taken from it.
+    /// * `source_alpha` - Whether the mask's alpha channel is used, otherwise its luminance is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mask can't be converted into a `DynamicImage`.
+    pub fn from_mask(mask: &Image, source_alpha: bool) -> Result<Self, IntoDynamicImageError> {
+        let channel = if source_alpha {
+            ColorChannel::Alpha
+        } else {
+            ColorChannel::Luminance
+        };
+        EdgesBuilder::new().color_channel(channel).image(mask)
+    }
+
+    /// Creates a new `Builder` from a Bevy image without validating its texture format.
+    ///
+    /// The pixel data is read as RGBA8 and pixels with a non-zero alpha value are treated as opaque.
+    /// This is intended for hot paths such as level streaming, where the format was already validated
+    /// when the image was loaded.
+    ///
+    /// # Safety
+    ///
+    /// The image data must hold at least `width * height * 4` bytes, which is the case for every image
+    /// with a 4-byte RGBA or BGRA texture format. Reading an image with a smaller pixel size is undefined behavior.
+    #[must_use]
+    pub unsafe fn from_image_unchecked(image: &Image) -> Self {
+        let (width, height) = (image.width(), image.height());
+        let pixels: Vec<u8> = (0..width as usize * height as usize)
+            // SAFETY: the caller guarantees that the data holds four bytes per pixel.
+            .map(|index| u8::from(unsafe { *image.data.get_unchecked(index * 4 + 3) } != 0))
+            .collect();
+        Self::new(from_pixels(width, height, &pixels))
+    }
+
+    /// Creates a new `Builder` from an `OpenEXR` heightmap, as exported by terrain baking tools.
+    ///
+    /// Pixels whose luminance exceeds `threshold` are treated as opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The encoded `OpenEXR` file.
+    /// * `threshold` - The luminance threshold, usually in the range `0.0..=1.0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` can't be decoded as an `OpenEXR` image.
+    #[cfg(feature = "exr")]
+    pub fn from_exr(data: &[u8], threshold: f32) -> image::ImageResult<Self> {
+        let image =
+            image::load_from_memory_with_format(data, image::ImageFormat::OpenExr)?.to_luma32f();
+        let pixels: Vec<u8> = image
+            .pixels()
+            .map(|pixel| u8::from(pixel[0] > threshold))
+            .collect();
+        Ok(Self::new(from_pixels(
+            image.width(),
+            image.height(),
+            &pixels,
+        )))
+    }
+
+    /// Creates a new `Builder` from an encoded TGA image, as exported by older engines and texture pipelines.
+    ///
+    /// Pixels with a non-zero alpha value are treated as opaque, images without an alpha channel are fully opaque.
+    /// Use [`EdgesBuilder`] with [`ColorChannel::Luminance`] for other masks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` can't be decoded as a TGA image.
+    #[cfg(feature = "tga")]
+    pub fn from_tga_bytes(data: &[u8]) -> image::ImageResult<Self> {
+        image::load_from_memory_with_format(data, image::ImageFormat::Tga)
+            .map(|image| EdgesBuilder::new().dynamic_image(&image))
+    }
+
+    /// Creates a new `Builder` from an encoded BMP image.
+    ///
+    /// Pixels with a non-zero alpha value are treated as opaque, images without an alpha channel are fully opaque.
+    /// Use [`EdgesBuilder`] with [`ColorChannel::Luminance`] for other masks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` can't be decoded as a BMP image.
+    #[cfg(feature = "bmp")]
+    pub fn from_bmp_bytes(data: &[u8]) -> image::ImageResult<Self> {
+        image::load_from_memory_with_format(data, image::ImageFormat::Bmp)
+            .map(|image| EdgesBuilder::new().dynamic_image(&image))
+    }
+
+    /// Creates a new `Builder` by rasterizing SVG path data.
+    ///
+    /// The path is rasterized into an image of `width` × `height` pixels using the even-odd fill rule,
+    /// with path coordinates in pixels and the origin at the top-left corner. Curves and arcs are
+    /// flattened into line segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path data, such as `"M 10 20 L 30 40 L 10 40 Z"`.
+    /// * `width` - The width of the rasterized image in pixels.
+    /// * `height` - The height of the rasterized image in pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `path` isn't valid SVG path data.
+    #[cfg(feature = "svg")]
+    pub fn from_svg_path(path: &str, width: u32, height: u32) -> Result<Self, svgtypes::Error> {
+        let polygons = svg::polygons(path)?;
+        Ok(Self::new(rasterize(&polygons, width, height)))
+    }
+}
+
+/// Creates a new `Builder` from a closed polygon.
+///
+/// See the `From<Vec<Vec<Vec2>>>` implementation.
+impl From<Vec<Vec2>> for Builder<BinaryImage> {
+    fn from(polygon: Vec<Vec2>) -> Self {
+        Self::from(vec![polygon])
+    }
+}
+
+/// Creates a new `Builder` from closed polygons, one per object.
+///
+/// Polygon collider types keep the points of the polygons exactly, in their original coordinates,
+/// with only the minimum area, sorting, vertex budget, scale and winding order settings applied.
+///
+/// For other collider types, the polygons are rasterized into an image covering their bounding box,
+/// with one pixel per unit and y pointing up. A pixel is opaque if its center lies inside the polygons
+/// according to the even-odd rule, so generated colliders are centered at the center of the bounding box.
+impl From<Vec<Vec<Vec2>>> for Builder<BinaryImage> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from(polygons: Vec<Vec<Vec2>>) -> Self {
+        let points: Vec<Vec2> = polygons.iter().flatten().copied().collect();
+        let Some((min, max)) = geometry::bounding_box(&points) else {
+            return Self::new(BinaryImage::new(0, 0));
+        };
+        let (width, height) = ((max.x - min.x).ceil() as u32, (max.y - min.y).ceil() as u32);
+        let pixels: Vec<Vec<Vec2>> = polygons
+            .iter()
+            .map(|polygon| {
+                polygon
+                    .iter()
+                    .map(|point| Vec2::new(point.x - min.x, max.y - point.y))
+                    .collect()
+            })
+            .collect();
+        Self {
+            authored: Some(polygons),
+            ..Self::new(rasterize(&pixels, width, height))
+        }
+    }
+}
 
 impl<'a> From<&'a DynamicImage> for Builder<BinaryView<'a, DynamicImage>> {
     fn from(image: &'a DynamicImage) -> Self {
@@ -16,6 +334,11 @@ impl From<DynamicImage> for Builder<BinaryImage> {
     }
 }
 
+/// Creates a new `Builder` from a Bevy image.
+///
+/// Supported texture formats are `Rgba8UnormSrgb`, `Bgra8UnormSrgb`, `Bgra8Unorm` and `Rg8Unorm`,
+/// where pixels with a non-zero alpha value are opaque, and `R8Unorm`, where pixels with a non-zero value are opaque.
+/// Any other format, including `Rgba8Unorm`, results in an `IntoBinaryImageError::UnsupportedFormat` error.
 impl TryFrom<&Image> for Builder<BinaryImage> {
     type Error = binary_image::bevy::IntoBinaryImageError;
     fn try_from(image: &Image) -> Result<Self, Self::Error> {
@@ -23,6 +346,9 @@ impl TryFrom<&Image> for Builder<BinaryImage> {
     }
 }
 
+/// Creates a new `Builder` from a Bevy image.
+///
+/// See the `TryFrom<&Image>` implementation for the supported texture formats.
 impl TryFrom<Image> for Builder<BinaryImage> {
     type Error = binary_image::bevy::IntoBinaryImageError;
     fn try_from(image: Image) -> Result<Self, Self::Error> {
@@ -1,18 +1,52 @@
-use edges::{anchor::Anchor, binary_image::Bit, EdgesIter};
+use std::{cmp::Reverse, collections::HashSet};
+
+use bevy::{
+    prelude::*,
+    tasks::{AsyncComputeTaskPool, Task, TaskPool},
+    utils::tracing::warn,
+};
+use edges::{
+    anchor::Anchor,
+    binary_image::{BinaryImage, Bit},
+    utils::bounding_box,
+    EdgesIter,
+};
 use image::GenericImageView;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::{
-    abstract_collider::AbstractCollider, collider_type::ColliderType, utils::heights_and_scale,
+    abstract_collider::{AbstractCollider, BackendCollider, CompoundColliderBuilder, WindingOrder},
+    collider_type::ColliderType,
+    components::{components, count, merge_nearby, outline, ConnectedComponentsAlgo},
+    geometry,
+    heights::{heights_and_scale, resample_heights},
+    utils::{crop, downsample},
 };
 
 mod from;
 
+/// The maximum number of pixels of an image that generates [`ColliderType::PixelPerfect`] colliders.
+const PIXEL_PERFECT_LIMIT: u64 = 64 * 64;
+
 /// A builder for creating colliders from a image.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Builder<I: GenericImageView<Pixel = Bit>> {
     image: I,
+    /// The polygons the image was rasterized from, kept exactly for polygon collider types.
+    authored: Option<Vec<Vec<Vec2>>>,
     anchor: Anchor,
     collider_type: ColliderType,
+    scale: Vec2,
+    downsample: u32,
+    min_area: u32,
+    merge_distance: Option<f32>,
+    vertex_budget: Option<usize>,
+    exact_vertex_count: Option<usize>,
+    winding_order: Option<WindingOrder>,
+    algorithm: ConnectedComponentsAlgo,
+    sorted: bool,
+    only_largest: bool,
 }
 
 impl<I: GenericImageView<Pixel = Bit>> Builder<I> {
@@ -29,7 +63,18 @@ impl<I: GenericImageView<Pixel = Bit>> Builder<I> {
         Self {
             anchor: Anchor::Center(image.height(), image.width()),
             image,
+            authored: None,
             collider_type: ColliderType::default(),
+            scale: Vec2::ONE,
+            downsample: 1,
+            min_area: 0,
+            merge_distance: None,
+            vertex_budget: None,
+            exact_vertex_count: None,
+            winding_order: None,
+            algorithm: ConnectedComponentsAlgo::default(),
+            sorted: true,
+            only_largest: false,
         }
     }
 
@@ -122,47 +167,1093 @@ impl<I: GenericImageView<Pixel = Bit>> Builder<I> {
         self.with_type(ColliderType::Heightfield)
     }
 
-    /// Generates multiple colliders based on the current builder's settings.
+    /// Returns the current scale of the builder.
+    ///
+    /// # Returns
+    ///
+    /// The scale applied to generated colliders.
+    pub fn scale(&self) -> Vec2 {
+        self.scale
+    }
+
+    /// Sets a new scale for the builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale to be applied to generated colliders.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated scale.
     #[must_use]
-    pub fn multiple(&self) -> Vec<AbstractCollider> {
-        let iter = EdgesIter::new(&self.image);
-        if matches!(self.collider_type, ColliderType::Heightfield) {
-            iter.map(|polygon| {
-                let (heights, scale) = heights_and_scale(polygon, self.anchor);
-                AbstractCollider::Heightfield(heights, scale)
-            })
+    pub fn with_scale(self, scale: Vec2) -> Self {
+        Self { scale, ..self }
+    }
+    /// Scales generated colliders from pixels to world units.
+    #[must_use]
+    pub fn pixels_per_unit(self, pixels_per_unit: f32) -> Self {
+        self.with_scale(Vec2::splat(pixels_per_unit.recip()))
+    }
+
+    /// Sets a factor by which the image is shrunk before edge detection.
+    ///
+    /// Downsampling speeds up processing of large images at the cost of detail.
+    /// Generated colliders are scaled back to the original image space, a factor of `1` disables downsampling.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - The integer factor by which both image dimensions are divided.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated downsampling factor.
+    #[must_use]
+    pub fn downsample(self, factor: u32) -> Self {
+        Self {
+            downsample: factor.max(1),
+            ..self
+        }
+    }
+
+    /// Sets the minimum area of objects that generate colliders.
+    ///
+    /// Objects whose edge encloses fewer than `area` square pixels are ignored,
+    /// which is useful to discard noise such as stray pixels.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The minimum area in square pixels of the original image.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated minimum area.
+    #[must_use]
+    pub fn min_area(self, area: u32) -> Self {
+        Self {
+            min_area: area,
+            ..self
+        }
+    }
+
+    /// Sets the distance within which nearby objects are merged into a single object.
+    ///
+    /// Two objects are merged if the distance between their bounding boxes is at most `distance` pixels,
+    /// by concatenating their edge points. This is useful for sprites whose parts are separated by thin gaps,
+    /// and works best with [`ColliderType::ConvexHull`].
+    ///
+    /// # Arguments
+    ///
+    /// * `distance` - The maximum distance in pixels of the original image between merged objects.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated merge distance.
+    #[must_use]
+    pub fn merge_nearby_objects(self, distance: f32) -> Self {
+        Self {
+            merge_distance: Some(distance),
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of points of every generated collider.
+    ///
+    /// Colliders with more points are approximated with [`AbstractCollider::approximate`],
+    /// for aggressive budgets they may not match the original contour closely.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_vertices` - The maximum number of points, at least three points are kept.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated vertex budget.
+    #[must_use]
+    pub fn with_vertex_budget(self, max_vertices: usize) -> Self {
+        Self {
+            vertex_budget: Some(max_vertices),
+            ..self
+        }
+    }
+
+    /// Sets the number of points of every generated collider.
+    ///
+    /// Colliders with more points are simplified with [`AbstractCollider::simplify_to_n_points`],
+    /// which keeps exactly `vertices` points.
+    /// Unlike [`Builder::with_vertex_budget`], the points are chosen by their distance to the simplified edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - The number of points, at least three points are kept.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated vertex count.
+    #[must_use]
+    pub fn with_exact_vertex_count(self, vertices: usize) -> Self {
+        Self {
+            exact_vertex_count: Some(vertices),
+            ..self
+        }
+    }
+
+    /// Sets the winding order of every generated collider's points.
+    ///
+    /// Polygons whose winding doesn't match `order` are reversed, by default the points keep
+    /// the order in which the edges were traced.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The winding order of generated colliders.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated winding order.
+    #[must_use]
+    pub fn ensure_winding_order(self, order: WindingOrder) -> Self {
+        Self {
+            winding_order: Some(order),
+            ..self
+        }
+    }
+
+    /// Returns the current connected-components algorithm of the builder.
+    ///
+    /// # Returns
+    ///
+    /// The algorithm used to separate the image into objects.
+    pub fn connected_components_algorithm(&self) -> ConnectedComponentsAlgo {
+        self.algorithm
+    }
+
+    /// Sets the algorithm used to separate the opaque pixels of the image into objects.
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The connected-components algorithm, [`ConnectedComponentsAlgo::TwoPassUnionFind`] by default.
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance with the updated algorithm.
+    #[must_use]
+    pub fn with_connected_components_algorithm(self, algorithm: ConnectedComponentsAlgo) -> Self {
+        Self { algorithm, ..self }
+    }
+
+    /// Keeps the objects in the order they are found in the image instead of sorting them by area.
+    ///
+    /// By default, objects are sorted by their area in descending order, so the first collider is always
+    /// the largest object and the order doesn't change when unrelated pixels change. Without sorting,
+    /// objects are ordered by their topmost row, which matches the order of [`EdgesIter`].
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance that doesn't sort objects.
+    #[must_use]
+    pub fn no_sort(self) -> Self {
+        Self {
+            sorted: false,
+            ..self
+        }
+    }
+
+    /// Keeps only the largest object and discards the rest.
+    ///
+    /// The largest object is the one with the largest area, which is kept regardless of [`Builder::no_sort`].
+    ///
+    /// # Returns
+    ///
+    /// A new `Builder` instance that generates at most one collider.
+    #[must_use]
+    pub fn take_only_largest(self) -> Self {
+        Self {
+            only_largest: true,
+            ..self
+        }
+    }
+
+    /// Returns the polygons of all objects in the image, in original image pixel coordinates.
+    fn polygons(&self) -> Vec<Vec<UVec2>> {
+        let polygons = self.traced();
+        let mut polygons: Vec<Vec<UVec2>> = match self.merge_distance {
+            Some(distance) => merge_nearby(polygons.collect(), distance)
+                .into_iter()
+                .filter(|polygon| self.is_large_enough(polygon))
+                .collect(),
+            None => polygons
+                .filter(|polygon| self.is_large_enough(polygon))
+                .collect(),
+        };
+        if self.sorted || self.only_largest {
+            polygons.sort_by_cached_key(|polygon| Reverse(area(polygon).to_bits()));
+        }
+        if self.only_largest {
+            polygons.truncate(1);
+        }
+        polygons
+    }
+
+    /// Returns the polygon of the first object in the image, in original image pixel coordinates.
+    fn polygon(&self) -> Option<Vec<UVec2>> {
+        if self.sorted || self.only_largest || self.merge_distance.is_some() {
+            self.polygons().into_iter().next()
+        } else {
+            self.traced().find(|polygon| self.is_large_enough(polygon))
+        }
+    }
+
+    /// Traces the outer edge of every object.
+    fn traced(&self) -> Box<dyn Iterator<Item = Vec<UVec2>> + '_> {
+        let outlines = |components: Vec<(UVec2, BinaryImage)>| {
+            components
+                .into_iter()
+                .filter_map(|component| outline(&component))
+        };
+        let polygons: Box<dyn Iterator<Item = Vec<UVec2>>> =
+            match (self.algorithm, self.downsample > 1) {
+                (ConnectedComponentsAlgo::ContourTracing, false) => {
+                    Box::new(EdgesIter::new(&self.image))
+                }
+                (ConnectedComponentsAlgo::ContourTracing, true) => {
+                    let image = downsample(&self.image, self.downsample);
+                    Box::new(EdgesIter::new(&image).collect::<Vec<_>>().into_iter())
+                }
+                (algorithm, false) => Box::new(outlines(components(&self.image, algorithm))),
+                (algorithm, true) => Box::new(outlines(components(
+                    &downsample(&self.image, self.downsample),
+                    algorithm,
+                ))),
+            };
+        Box::new(polygons.map(|polygon| self.upsample(polygon)))
+    }
+
+    fn is_large_enough(&self, polygon: &[UVec2]) -> bool {
+        self.min_area == 0 || area(polygon) >= self.min_area as f32
+    }
+
+    /// Maps points of the downsampled image to the original image, rounding the centers of their blocks down.
+    ///
+    /// A point `p` maps to `(p + 0.5) * factor - 0.5`, the remaining half pixel of even factors
+    /// is added by [`Builder::translate`].
+    fn upsample(&self, polygon: Vec<UVec2>) -> Vec<UVec2> {
+        if self.downsample == 1 {
+            return polygon;
+        }
+        let offset = UVec2::splat((self.downsample - 1) / 2);
+        polygon
+            .into_iter()
+            .map(|point| point * self.downsample + offset)
             .collect()
+    }
+
+    /// Returns the offset of upsampled points that isn't a whole pixel, with y pointing up.
+    fn subpixel_offset(&self) -> Vec2 {
+        if self.downsample.is_multiple_of(2) {
+            Vec2::new(0.5, -0.5)
         } else {
-            self.anchor
-                .translate_polygons(iter)
+            Vec2::ZERO
+        }
+    }
+
+    /// Translates a polygon in image pixel coordinates into the coordinate space of the anchor.
+    fn translate(&self, polygon: Vec<UVec2>) -> Vec<Vec2> {
+        let offset = self.subpixel_offset();
+        let mut points = self.anchor.translate(polygon);
+        if offset != Vec2::ZERO {
+            for point in &mut points {
+                *point += offset;
+            }
+        }
+        points
+    }
+
+    /// Calculates the heights and scale of a heightfield from a polygon in image pixel coordinates.
+    fn heights(&self, polygon: Vec<UVec2>) -> (Vec<f32>, Vec2) {
+        let (mut heights, scale) = heights_and_scale(polygon, self.anchor);
+        let offset = self.subpixel_offset();
+        for height in &mut heights {
+            *height += offset.y;
+        }
+        (heights, scale)
+    }
+
+    /// Creates a collider of the current type from a polygon in image pixel coordinates.
+    ///
+    /// The polygon is translated, converted and scaled in place, without intermediate collections.
+    fn collider(&self, polygon: Vec<UVec2>) -> AbstractCollider {
+        let collider = match self.collider_type {
+            ColliderType::Heightfield => {
+                let (heights, scale) = self.heights(polygon);
+                AbstractCollider::Heightfield(heights, scale)
+            }
+            ColliderType::HeightfieldN { columns } => {
+                let (heights, scale) = self.heights(polygon);
+                AbstractCollider::Heightfield(resample_heights(&heights, columns), scale)
+            }
+            ColliderType::Polyline => AbstractCollider::Polyline(self.translate(polygon)),
+            ColliderType::ConvexPolyline => {
+                AbstractCollider::ConvexPolyline(self.translate(polygon))
+            }
+            ColliderType::ConvexHull => AbstractCollider::ConvexHull(self.translate(polygon)),
+            ColliderType::Trimesh => AbstractCollider::trimesh(self.translate(polygon)),
+            ColliderType::PixelPerfect => unreachable!(),
+        };
+        self.finish(collider)
+    }
+
+    /// Applies the vertex budget, exact vertex count, scale and winding order to a collider.
+    fn finish(&self, collider: AbstractCollider) -> AbstractCollider {
+        let collider = match self.vertex_budget {
+            Some(max_vertices) => collider.approximate(max_vertices),
+            None => collider,
+        };
+        let collider = match self.exact_vertex_count {
+            Some(vertices) => collider.simplify_to_n_points(vertices),
+            None => collider,
+        };
+        let collider = collider.scale(self.scale);
+        match self.winding_order {
+            Some(order) => collider.with_winding_order(order),
+            None => collider,
+        }
+    }
+
+    /// Creates colliders from the authored polygons, keeping their points exactly.
+    ///
+    /// Returns `None` if there are no authored polygons or the collider type isn't a polygon.
+    fn authored_colliders(&self) -> Option<Vec<AbstractCollider>> {
+        let polygons = self.authored.as_ref()?;
+        let collider: fn(Vec<Vec2>) -> AbstractCollider = match self.collider_type {
+            ColliderType::Polyline => AbstractCollider::Polyline,
+            ColliderType::ConvexPolyline => AbstractCollider::ConvexPolyline,
+            ColliderType::ConvexHull => AbstractCollider::ConvexHull,
+            ColliderType::Trimesh => AbstractCollider::trimesh,
+            _ => return None,
+        };
+        let area = |polygon: &Vec<Vec2>| geometry::signed_area(polygon).abs();
+        let mut polygons: Vec<&Vec<Vec2>> = polygons
+            .iter()
+            .filter(|polygon| self.min_area == 0 || area(polygon) >= self.min_area as f32)
+            .collect();
+        if self.sorted || self.only_largest {
+            polygons.sort_by(|a, b| area(b).total_cmp(&area(a)));
+        }
+        if self.only_largest {
+            polygons.truncate(1);
+        }
+        Some(
+            polygons
                 .into_iter()
-                .map(match self.collider_type {
-                    ColliderType::Polyline => AbstractCollider::Polyline,
-                    ColliderType::ConvexPolyline => AbstractCollider::ConvexPolyline,
-                    ColliderType::ConvexHull => AbstractCollider::ConvexHull,
-                    ColliderType::Heightfield => unreachable!(),
-                })
-                .collect()
+                .map(|polygon| self.finish(collider(polygon.clone())))
+                .collect(),
+        )
+    }
+
+    /// Creates a pixel-perfect `Trimesh` collider with two triangles per opaque pixel for every object.
+    ///
+    /// Images with more than [`PIXEL_PERFECT_LIMIT`] pixels don't generate colliders and log a warning.
+    fn pixel_perfect(&self) -> impl Iterator<Item = AbstractCollider> + '_ {
+        let (width, height) = self.image.dimensions();
+        let mut components = if u64::from(width) * u64::from(height) <= PIXEL_PERFECT_LIMIT {
+            components(&self.image, self.algorithm)
+        } else {
+            warn!(
+                "{width}×{height} image exceeds the pixel-perfect limit of \
+                 {PIXEL_PERFECT_LIMIT} pixels, no colliders generated"
+            );
+            Vec::new()
+        };
+        if self.sorted || self.only_largest {
+            components.sort_by_cached_key(|(_, image)| {
+                Reverse(image.pixels().filter(|(_, _, pixel)| **pixel).count())
+            });
+        }
+        if self.only_largest {
+            components.truncate(1);
         }
+        components.into_iter().map(|(offset, image)| {
+            let pixels: Vec<UVec2> = image
+                .pixels()
+                .filter(|(_, _, pixel)| **pixel)
+                .map(|(x, y, _)| offset + UVec2::new(x, y))
+                .collect();
+            let mut vertices = Vec::with_capacity(pixels.len() * 4);
+            let mut indices = Vec::with_capacity(pixels.len() * 2);
+            for center in self.anchor.translate(pixels) {
+                let [a, b, c, d] = [
+                    Vec2::new(-0.5, -0.5),
+                    Vec2::new(0.5, -0.5),
+                    Vec2::new(0.5, 0.5),
+                    Vec2::new(-0.5, 0.5),
+                ]
+                .map(|corner| center + corner);
+                let first = u32::try_from(vertices.len()).unwrap_or(u32::MAX);
+                vertices.extend([a, b, c, d]);
+                indices.extend([[first, first + 1, first + 2], [first, first + 2, first + 3]]);
+            }
+            AbstractCollider::Trimesh(vertices, indices).scale(self.scale)
+        })
     }
+
+    /// Generates multiple colliders based on the current builder's settings.
+    ///
+    /// [`ColliderType::PixelPerfect`] generates nothing for images larger than 64×64 pixels.
+    #[must_use]
+    pub fn multiple(&self) -> Vec<AbstractCollider> {
+        if let Some(colliders) = self.authored_colliders() {
+            return colliders;
+        }
+        if self.collider_type == ColliderType::PixelPerfect {
+            return self.pixel_perfect().collect();
+        }
+        self.polygons()
+            .into_iter()
+            .map(|polygon| self.collider(polygon))
+            .collect()
+    }
+
     /// Generates a single collider based on the current builder's settings.
+    ///
+    /// [`ColliderType::PixelPerfect`] returns `None` for images larger than 64×64 pixels.
     #[must_use]
     pub fn single(&self) -> Option<AbstractCollider> {
-        let polygon = EdgesIter::new(&self.image).next();
-        if matches!(self.collider_type, ColliderType::Heightfield) {
-            polygon.map(|polygon| {
-                let (heights, scale) = heights_and_scale(polygon, self.anchor);
-                AbstractCollider::Heightfield(heights, scale)
+        if let Some(colliders) = self.authored_colliders() {
+            return colliders.into_iter().next();
+        }
+        if self.collider_type == ColliderType::PixelPerfect {
+            return self.pixel_perfect().next();
+        }
+        self.polygon().map(|polygon| self.collider(polygon))
+    }
+
+    /// Finds the object whose collider contains a point.
+    ///
+    /// The point is in the coordinate space of the generated colliders, after applying the anchor and scale.
+    /// `Heightfield` colliders don't enclose an area and never contain a point.
+    ///
+    /// # Returns
+    ///
+    /// The index of the first collider returned by [`Builder::multiple`] containing the point,
+    /// or `None` if no collider contains it.
+    #[must_use]
+    pub fn find_object_at_point(&self, point: Vec2) -> Option<usize> {
+        self.multiple()
+            .iter()
+            .position(|collider| collider.contains_point(point))
+    }
+
+    /// Finds the point on any object's edge that is closest to a point.
+    ///
+    /// The point is in the coordinate space of the generated colliders, after applying the anchor and scale.
+    /// Every segment of every collider is checked, see [`AbstractCollider::closest_point`].
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the index of the collider returned by [`Builder::multiple`], the closest point
+    /// and its distance to `point`, or `None` if there are no colliders with points.
+    #[must_use]
+    pub fn closest_point_on_edge(&self, point: Vec2) -> Option<(usize, Vec2, f32)> {
+        self.multiple()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, collider)| {
+                collider
+                    .closest_point(point)
+                    .map(|(closest, distance)| (index, closest, distance))
             })
-        } else {
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+    }
+
+    /// Returns all segments of the objects' edges as `(start, end)` pairs.
+    ///
+    /// The segments are in the coordinate space of the generated colliders, after applying the anchor and scale,
+    /// and the objects are in the same order as the colliders returned by [`Builder::multiple`].
+    /// Every edge is closed, its last segment ends at the start of its first segment.
+    pub fn segments(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        self.polygons().into_iter().flat_map(|polygon| {
+            let points: Vec<Vec2> = self
+                .translate(polygon)
+                .into_iter()
+                .map(|point| point * self.scale)
+                .collect();
+            let ends = points.iter().skip(1).chain(points.first());
+            points
+                .iter()
+                .copied()
+                .zip(ends.copied())
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Returns the bounding rectangles of all objects in image pixel coordinates.
+    ///
+    /// The rectangles use the image's coordinate system with y pointing down, and are in the same
+    /// order as the colliders returned by [`Builder::multiple`]. This is useful for positioning
+    /// sprites when splitting an image with multiple objects into separate entities.
+    ///
+    /// # Returns
+    ///
+    /// A vector of rectangles, each covering all pixels of an object's edge.
+    #[must_use]
+    pub fn pixel_bounds(&self) -> Vec<URect> {
+        self.polygons()
+            .into_iter()
+            .filter_map(|polygon| bounding_box(polygon.into_iter()))
+            .map(|(min, max)| URect::from_corners(min, max + UVec2::ONE))
+            .collect()
+    }
+
+    /// Counts the objects in the image without generating colliders.
+    ///
+    /// The pixels are only labeled, without tracing edges or creating colliders, which makes this faster
+    /// than counting the colliders returned by [`Builder::multiple`]. With
+    /// [`ConnectedComponentsAlgo::ContourTracing`] the edges are traced, so objects inside holes aren't counted.
+    ///
+    /// # Returns
+    ///
+    /// The number of objects in the full-resolution image, without downsampling, area filtering or merging.
+    #[must_use]
+    pub fn object_count(&self) -> usize {
+        match self.algorithm {
+            ConnectedComponentsAlgo::ContourTracing => EdgesIter::new(&self.image).count(),
+            algorithm => count(&self.image, algorithm),
+        }
+    }
+
+    /// Counts the opaque pixels in every cell of a `resolution` × `resolution` grid over the image.
+    ///
+    /// This is useful for procedural mechanics such as giving denser areas more mass, and for visual effects.
+    /// The cells split the image as evenly as possible, rows are ordered from the top of the image to the bottom,
+    /// and cells from left to right. Cells don't contain pixels if `resolution` exceeds the image's size.
+    ///
+    /// # Returns
+    ///
+    /// The number of opaque pixels per cell, indexed as `[row][column]`.
+    #[must_use]
+    pub fn density_map(&self, resolution: u32) -> Vec<Vec<u32>> {
+        let (width, height) = self.image.dimensions();
+        let mut map = vec![vec![0; resolution as usize]; resolution as usize];
+        if resolution == 0 {
+            return map;
+        }
+        // The cell index is lower than `resolution`, so it always fits into `usize`.
+        let cell = |position: u32, size: u32| {
+            usize::try_from(u64::from(position) * u64::from(resolution) / u64::from(size))
+                .unwrap_or_default()
+        };
+        for y in 0..height {
+            for x in 0..width {
+                if *self.image.get_pixel(x, y) {
+                    map[cell(y, height)][cell(x, width)] += 1;
+                }
+            }
+        }
+        map
+    }
+
+    /// Returns the number of pixels on the boundary of an object, in image pixels.
+    ///
+    /// Unlike the perimeter of a collider's polygon, the count doesn't depend on the collider type
+    /// or scale, which is useful for estimating roughness and for comparing objects detected in images
+    /// of different resolutions. The boundary pixels are the distinct pixels the object's outline
+    /// passes through, stepping like [`Builder::chain_code`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the object's collider returned by [`Builder::multiple`].
+    ///
+    /// # Returns
+    ///
+    /// The number of boundary pixels, or `None` if there is no object at `index`.
+    #[must_use]
+    pub fn pixel_perimeter(&self, index: usize) -> Option<u32> {
+        let polygon = self.polygons().into_iter().nth(index)?;
+        let ends = polygon.iter().skip(1).chain(polygon.first());
+        let mut pixels = HashSet::new();
+        for (start, end) in polygon.iter().zip(ends) {
+            let delta = end.as_ivec2() - start.as_ivec2();
+            let steps = delta.abs().max_element().max(1);
+            pixels.extend((0..steps).map(|step| start.as_ivec2() + delta.signum() * step));
+        }
+        Some(u32::try_from(pixels.len()).unwrap_or(u32::MAX))
+    }
+
+    /// Finds the object with the longest perimeter.
+    ///
+    /// The perimeter of every object's polygon is measured in image pixels.
+    ///
+    /// # Returns
+    ///
+    /// The index of the object's collider returned by [`Builder::multiple`], or `None` if there are no objects.
+    #[must_use]
+    pub fn longest_edge_object(&self) -> Option<usize> {
+        self.largest_object_by(geometry::perimeter)
+    }
+
+    /// Finds the object whose polygon encloses the largest area.
+    ///
+    /// The area of every object's polygon is measured in square image pixels.
+    ///
+    /// # Returns
+    ///
+    /// The index of the object's collider returned by [`Builder::multiple`], or `None` if there are no objects.
+    #[must_use]
+    pub fn largest_area_object(&self) -> Option<usize> {
+        self.largest_object_by(|polygon| geometry::signed_area(polygon).abs())
+    }
+
+    /// Checks whether the edge of an object is a simple polygon, i.e. doesn't intersect or touch itself.
+    ///
+    /// Complex sprites may produce figure-eight shapes, where an object touches itself at a single pixel corner.
+    /// Non-simple polygons result in incorrect convex hulls, triangulations and convex decompositions.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the object's collider returned by [`Builder::multiple`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the object's polygon is simple, `false` if it isn't or there is no object at `index`.
+    #[must_use]
+    pub fn is_simple_polygon(&self, index: usize) -> bool {
+        self.polygons().get(index).is_some_and(|polygon| {
+            geometry::is_simple(&polygon.iter().map(UVec2::as_vec2).collect::<Vec<_>>())
+        })
+    }
+
+    /// Returns the index of the polygon with the largest value of `measure`.
+    fn largest_object_by(&self, measure: impl Fn(&[Vec2]) -> f32) -> Option<usize> {
+        self.polygons()
+            .iter()
+            .map(|polygon| measure(&polygon.iter().map(UVec2::as_vec2).collect::<Vec<_>>()))
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    /// Generates a single backend collider based on the current builder's settings.
+    ///
+    /// # Returns
+    ///
+    /// The collider of the backend `C`, or `None` if no object was found or the backend can't represent it.
+    #[must_use]
+    pub fn build<C: BackendCollider>(&self) -> Option<C> {
+        self.single().and_then(C::from_abstract)
+    }
+
+    /// Generates a compound of the colliders of all objects based on the current builder's settings.
+    ///
+    /// The colliders keep their positions relative to the anchor, which makes the compound
+    /// suitable for static level geometry that should be a single physics body.
+    /// Composite shapes can't be nested in a compound, so `Polyline` and `Trimesh` colliders
+    /// are split into `ConvexPolyline` parts with [`AbstractCollider::to_convex_partition`].
+    ///
+    /// # Returns
+    ///
+    /// A `CompoundColliderBuilder` containing the colliders returned by [`Builder::multiple`].
+    #[must_use]
+    pub fn compound(&self) -> CompoundColliderBuilder {
+        self.multiple()
+            .into_iter()
+            .flat_map(|collider| match collider {
+                AbstractCollider::Polyline(_) | AbstractCollider::Trimesh(_, _) => {
+                    collider.to_convex_partition().map_or_else(
+                        || vec![collider],
+                        |parts| {
+                            parts
+                                .into_iter()
+                                .map(AbstractCollider::ConvexPolyline)
+                                .collect()
+                        },
+                    )
+                }
+                collider => vec![collider],
+            })
+            .fold(CompoundColliderBuilder::new(), |compound, collider| {
+                compound.with_collider(collider, Vec2::ZERO)
+            })
+    }
+
+    /// Creates a new `Builder` for a rectangular region of the image.
+    ///
+    /// The region is clamped to the bounds of the image. The new builder keeps all current settings,
+    /// and centered anchors are moved to the center of the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `rect` - The region of the image in pixel coordinates, with the origin at the top-left corner.
+    ///
+    /// # Returns
+    ///
+    /// A `Builder` instance for the region, or `None` if the region doesn't overlap the image.
+    #[must_use]
+    pub fn region(&self, rect: URect) -> Option<Builder<BinaryImage>> {
+        let rect = rect.intersect(URect::new(0, 0, self.image.width(), self.image.height()));
+        (!rect.is_empty()).then(|| {
+            let image = crop(
+                &self.image,
+                rect.min.x,
+                rect.min.y,
+                rect.width(),
+                rect.height(),
+            );
+            self.with_image(image)
+        })
+    }
+
+    /// Creates a new `Builder` for another image with the current settings.
+    ///
+    /// All fields are listed, so a new setting can't be forgotten here.
+    fn with_image<J: GenericImageView<Pixel = Bit>>(&self, image: J) -> Builder<J> {
+        let Self {
+            image: _,
+            authored: _,
+            anchor,
+            collider_type,
+            scale,
+            downsample,
+            min_area,
+            merge_distance,
+            vertex_budget,
+            exact_vertex_count,
+            winding_order,
+            algorithm,
+            sorted,
+            only_largest,
+        } = *self;
+        let anchor = match anchor {
+            Anchor::Center(_, _) => Anchor::Center(image.height(), image.width()),
+            Anchor::VerticalCenter(_) => Anchor::VerticalCenter(image.height()),
+            Anchor::HorisontalCenter(_) => Anchor::HorisontalCenter(image.width()),
+            Anchor::AbsoluteCenter => Anchor::AbsoluteCenter,
+        };
+        Builder {
+            image,
+            authored: None,
+            anchor,
+            collider_type,
+            scale,
+            downsample,
+            min_area,
+            merge_distance,
+            vertex_budget,
+            exact_vertex_count,
+            winding_order,
+            algorithm,
+            sorted,
+            only_largest,
+        }
+    }
+
+    /// Generates colliders for every tile of a tile atlas image.
+    ///
+    /// The image is split into tiles of `tile_width` × `tile_height` pixels starting at the top-left corner,
+    /// tiles on the right and bottom borders may be smaller. Each tile is processed with the current
+    /// settings and anchored at its own center.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(tile_x, tile_y, colliders)` for each tile that contains opaque pixels,
+    /// where `tile_x` and `tile_y` are the column and row of the tile.
+    #[must_use]
+    pub fn tiles(
+        &self,
+        tile_width: u32,
+        tile_height: u32,
+    ) -> Vec<(u32, u32, Vec<AbstractCollider>)> {
+        if tile_width == 0 || tile_height == 0 {
+            return Vec::new();
+        }
+        let (width, height) = self.image.dimensions();
+        (0..height.div_ceil(tile_height))
+            .flat_map(|tile_y| (0..width.div_ceil(tile_width)).map(move |tile_x| (tile_x, tile_y)))
+            .filter_map(|(tile_x, tile_y)| {
+                let min = UVec2::new(tile_x * tile_width, tile_y * tile_height);
+                let colliders = self
+                    .region(URect::from_corners(
+                        min,
+                        min + UVec2::new(tile_width, tile_height),
+                    ))?
+                    .multiple();
+                (!colliders.is_empty()).then_some((tile_x, tile_y, colliders))
+            })
+            .collect()
+    }
+
+    /// Encodes the edge of an object as a Freeman chain code.
+    ///
+    /// The closed edge is traversed in steps of one pixel, each encoded as one of eight directions in image
+    /// coordinates: `0` is east, `1` north-east, `2` north, `3` north-west, `4` west, `5` south-west,
+    /// `6` south and `7` south-east, where north points towards the top of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the object's collider returned by [`Builder::multiple`].
+    ///
+    /// # Returns
+    ///
+    /// The directions of all steps, or `None` if there is no object at `index`.
+    #[must_use]
+    pub fn chain_code(&self, index: usize) -> Option<Vec<u8>> {
+        let polygon = self.polygons().into_iter().nth(index)?;
+        let ends = polygon.iter().skip(1).chain(polygon.first());
+        Some(
             polygon
-                .map(|polygon| self.anchor.translate(polygon))
-                .map(match self.collider_type {
-                    ColliderType::Polyline => AbstractCollider::Polyline,
-                    ColliderType::ConvexPolyline => AbstractCollider::ConvexPolyline,
-                    ColliderType::ConvexHull => AbstractCollider::ConvexHull,
-                    ColliderType::Heightfield => unreachable!(),
+                .iter()
+                .zip(ends)
+                .flat_map(|(start, end)| {
+                    let delta = end.as_ivec2() - start.as_ivec2();
+                    let direction = match (delta.x.signum(), delta.y.signum()) {
+                        (1, 0) => 0,
+                        (1, -1) => 1,
+                        (0, -1) => 2,
+                        (-1, -1) => 3,
+                        (-1, 0) => 4,
+                        (-1, 1) => 5,
+                        (0, 1) => 6,
+                        _ => 7,
+                    };
+                    let steps = delta.abs().max_element().unsigned_abs() as usize;
+                    std::iter::repeat_n(direction, steps)
                 })
+                .collect(),
+        )
+    }
+}
+
+impl<I: GenericImageView<Pixel = Bit> + Sync> Builder<I> {
+    /// Generates the colliders of every builder in a batch.
+    ///
+    /// With the `parallel` feature the builders are processed concurrently, otherwise one after another.
+    ///
+    /// # Returns
+    ///
+    /// A vector with the colliders of each builder, in the order of `builders`.
+    #[must_use]
+    pub fn batch(builders: &[Self]) -> Vec<Vec<AbstractCollider>> {
+        #[cfg(feature = "parallel")]
+        let iter = builders.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = builders.iter();
+
+        iter.map(Self::multiple).collect()
+    }
+}
+
+impl<I: GenericImageView<Pixel = Bit> + Send + 'static> Builder<I> {
+    /// Generates multiple colliders on the [`AsyncComputeTaskPool`], so complex images don't stall a frame.
+    ///
+    /// The task can be polled to completion in a system, for example with
+    /// `block_on(future::poll_once(&mut task))`, before inserting the colliders.
+    ///
+    /// # Returns
+    ///
+    /// A task that resolves to the colliders returned by [`Builder::multiple`].
+    pub fn multiple_async(self) -> Task<Vec<AbstractCollider>> {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(async move { self.multiple() })
+    }
+
+    /// Generates a single collider on the [`AsyncComputeTaskPool`], so complex images don't stall a frame.
+    ///
+    /// # Returns
+    ///
+    /// A task that resolves to the collider returned by [`Builder::single`].
+    pub fn single_async(self) -> Task<Option<AbstractCollider>> {
+        AsyncComputeTaskPool::get_or_init(TaskPool::default).spawn(async move { self.single() })
+    }
+}
+
+/// Returns the area enclosed by a polygon in image pixel coordinates.
+fn area(polygon: &[UVec2]) -> f32 {
+    let polygon: Vec<Vec2> = polygon.iter().map(UVec2::as_vec2).collect();
+    geometry::signed_area(&polygon).abs()
+}
+
+impl<I: GenericImageView<Pixel = Bit>> std::fmt::Debug for Builder<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = self.image.dimensions();
+        f.debug_struct("Builder")
+            .field("dimensions", &format_args!("{width}x{height}"))
+            .field("authored", &self.authored.as_ref().map(Vec::len))
+            .field("anchor", &self.anchor)
+            .field("collider_type", &self.collider_type)
+            .field("scale", &self.scale)
+            .field("downsample", &self.downsample)
+            .field("min_area", &self.min_area)
+            .field("merge_distance", &self.merge_distance)
+            .field("vertex_budget", &self.vertex_budget)
+            .field("exact_vertex_count", &self.exact_vertex_count)
+            .field("winding_order", &self.winding_order)
+            .field("algorithm", &self.algorithm)
+            .field("sorted", &self.sorted)
+            .field("only_largest", &self.only_largest)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::bounding::BoundingVolume;
+
+    use super::*;
+
+    /// Creates a builder from rows of `#` for opaque and `.` for transparent pixels.
+    fn builder(rows: &[&str]) -> Builder<BinaryImage> {
+        let width = u32::try_from(rows[0].len()).unwrap();
+        let height = u32::try_from(rows.len()).unwrap();
+        let cells: Vec<u8> = rows.iter().flat_map(|row| row.bytes()).collect();
+        Builder::from_int_grid(&cells, width, height, b"#").unwrap()
+    }
+
+    #[test]
+    fn region_keeps_settings() {
+        let builder = builder(&[
+            "##......", "##......", "..####..", "..####..", "..####..", "..####..",
+        ])
+        .min_area(4);
+        assert_eq!(
+            builder
+                .clone()
+                .region(URect::new(0, 0, 8, 6))
+                .unwrap()
+                .multiple()
+                .len(),
+            1
+        );
+        assert_eq!(
+            builder
+                .region(URect::new(0, 0, 2, 2))
+                .unwrap()
+                .multiple()
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn tiles_keep_settings() {
+        let builder = builder(&["##..####", "##..####", "....####", "....####"]);
+        let tiles = |builder: &Builder<BinaryImage>| -> Vec<(u32, u32)> {
+            builder
+                .tiles(4, 4)
+                .into_iter()
+                .map(|(x, y, _)| (x, y))
+                .collect()
+        };
+        assert_eq!(tiles(&builder), [(0, 0), (1, 0)]);
+        assert_eq!(tiles(&builder.min_area(4)), [(1, 0)]);
+    }
+
+    #[test]
+    fn compound_of_concave_objects() {
+        let builder = builder(&["##..##..", "##..##..", "######..", "######.#"]);
+        for builder in [builder.clone(), builder.with_type(ColliderType::Trimesh)] {
+            let compound = builder.compound();
+            assert!(compound
+                .colliders()
+                .iter()
+                .all(|(_, collider)| matches!(collider, AbstractCollider::ConvexPolyline(_))));
+            #[cfg(feature = "rapier2d")]
+            assert!(compound.clone().to_rapier().is_some());
+            #[cfg(feature = "avian2d")]
+            assert!(compound.to_avian().is_some());
         }
     }
+
+    #[test]
+    fn downsample_matches_full_resolution() {
+        let mut rows = vec!["........................"; 4];
+        rows.extend(["....################...."; 8]);
+        rows.extend(["........................"; 4]);
+        let builder = builder(&rows).convex_hull();
+        let bounds = |builder: Builder<BinaryImage>| builder.single().unwrap().aabb_2d().unwrap();
+
+        let full = bounds(builder.clone());
+        for factor in [2, 4] {
+            let downsampled = bounds(builder.clone().downsample(factor));
+            // Outlines run through the centers of the blocks, which are inset by half a block.
+            let inset = Vec2::splat((factor - 1) as f32 / 2.);
+            assert_eq!(downsampled.center(), full.center(), "factor {factor}");
+            assert_eq!(downsampled.min, full.min + inset, "factor {factor}");
+        }
+    }
+
+    #[test]
+    fn pixel_perfect_limit() {
+        let opaque = |size: u32| {
+            let cells = vec![b'#'; size as usize * size as usize];
+            Builder::from_int_grid(&cells, size, size, b"#")
+                .unwrap()
+                .with_type(ColliderType::PixelPerfect)
+        };
+        assert!(opaque(64).single().is_some());
+        assert!(opaque(65).single().is_none());
+    }
+
+    #[test]
+    fn pixel_perimeter_follows_multiple() {
+        let builder = builder(&["###...", "......", "..####", "..####", "..####"]);
+        assert_eq!(builder.multiple().len(), 2);
+        assert_eq!(builder.pixel_perimeter(0), Some(10));
+        assert_eq!(builder.pixel_perimeter(1), Some(3));
+        assert_eq!(builder.pixel_perimeter(2), None);
+    }
+
+    #[test]
+    fn authored_polygons_are_kept() {
+        let square = vec![
+            Vec2::new(0., 0.),
+            Vec2::new(1., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(0., 1.),
+        ];
+        let triangle = vec![Vec2::new(0., 0.), Vec2::new(2., 0.), Vec2::new(0., 2.)];
+        for polygon in [square, triangle] {
+            for collider_type in [
+                ColliderType::Polyline,
+                ColliderType::ConvexPolyline,
+                ColliderType::ConvexHull,
+                ColliderType::Trimesh,
+            ] {
+                let builder = Builder::from(polygon.clone()).with_type(collider_type);
+                let collider = builder.single().unwrap();
+                assert_eq!(collider.points(), Some(&polygon), "{collider_type:?}");
+                assert_eq!(builder.multiple().len(), 1, "{collider_type:?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn svg_rect_path() {
+        let builder = Builder::from_svg_path("M 2 3 H 10 V 7 H 2 Z", 12, 10)
+            .unwrap()
+            .convex_hull();
+        let colliders = builder.multiple();
+        assert_eq!(colliders.len(), 1);
+        assert!(matches!(colliders[0], AbstractCollider::ConvexHull(_)));
+        // The outline runs through the centers of the 8×4 pixels inside the rect.
+        let aabb = colliders[0].aabb_2d().unwrap();
+        assert_eq!(aabb.min, Vec2::new(-3.5, -1.5));
+        assert_eq!(aabb.max, Vec2::new(3.5, 1.5));
+        assert_eq!(builder.pixel_bounds(), [URect::new(2, 3, 10, 7)]);
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn svg_path_with_hole() {
+        let builder =
+            Builder::from_svg_path("M 1 1 H 11 V 11 H 1 Z M 4 4 H 8 V 8 H 4 Z", 12, 12).unwrap();
+        // With the even-odd rule the inner loop cuts a hole into the outer one.
+        assert!(builder.image.get_pixel(2, 2).0);
+        assert!(!builder.image.get_pixel(6, 6).0);
+        assert_eq!(builder.multiple().len(), 1);
+    }
+
+    #[cfg(feature = "svg")]
+    #[test]
+    fn malformed_svg_path() {
+        assert!(Builder::from_svg_path("M 1 1 L", 12, 12).is_err());
+        assert!(Builder::from_svg_path("M 1 1 X 2 2", 12, 12).is_err());
+    }
 }
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use super::AbstractCollider;
+
+/// A builder for assembling multiple abstract colliders into a single compound collider.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompoundColliderBuilder {
+    colliders: Vec<(Vec2, AbstractCollider)>,
+}
+
+impl CompoundColliderBuilder {
+    /// Creates a new, empty `CompoundColliderBuilder`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the colliders added to the builder together with their offsets.
+    #[must_use]
+    pub fn colliders(&self) -> &[(Vec2, AbstractCollider)] {
+        &self.colliders
+    }
+
+    /// Adds a collider to the compound.
+    ///
+    /// # Arguments
+    ///
+    /// * `collider` - The collider to be added.
+    /// * `offset` - The position of the collider relative to the compound's origin.
+    ///
+    /// # Returns
+    ///
+    /// A new `CompoundColliderBuilder` instance containing the added collider.
+    #[must_use]
+    pub fn with_collider(mut self, collider: AbstractCollider, offset: Vec2) -> Self {
+        self.colliders.push((offset, collider));
+        self
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::abstract_collider::AbstractCollider::{ConvexHull, ConvexPolyline};
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0., 0.),
+            Vec2::new(1., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(0., 1.),
+        ]
+    }
+
+    /// A compound of two convex unit squares, which every backend can represent.
+    pub(crate) fn convex_compound() -> CompoundColliderBuilder {
+        CompoundColliderBuilder::new()
+            .with_collider(ConvexPolyline(square()), Vec2::ZERO)
+            .with_collider(ConvexHull(square()), Vec2::X)
+    }
+
+    /// Compounds of one composite collider each, which backends can't nest in a compound.
+    #[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+    pub(crate) fn composite_compounds() -> [CompoundColliderBuilder; 2] {
+        [
+            CompoundColliderBuilder::new()
+                .with_collider(AbstractCollider::Polyline(square()), Vec2::ZERO),
+            CompoundColliderBuilder::new()
+                .with_collider(AbstractCollider::trimesh(square()), Vec2::ZERO),
+        ]
+    }
+
+    #[test]
+    fn colliders_keep_order_and_offsets() {
+        let compound = convex_compound();
+        assert_eq!(
+            compound.colliders(),
+            [
+                (Vec2::ZERO, ConvexPolyline(square())),
+                (Vec2::X, ConvexHull(square())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use super::{AbstractCollider, CompoundColliderBuilder};
+
+impl AbstractCollider {
+    /// Serializes the collider into a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collider contains values JSON can't represent, such as non-finite numbers.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a collider from a JSON string created by [`AbstractCollider::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid JSON representation of a collider.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes a list of colliders into a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a collider contains values JSON can't represent, such as non-finite numbers.
+    pub fn multiple_to_json(colliders: &[Self]) -> serde_json::Result<String> {
+        serde_json::to_string(colliders)
+    }
+
+    /// Deserializes a list of colliders from a JSON string, as written by the CLI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid JSON array of colliders.
+    pub fn multiple_from_json(json: &str) -> serde_json::Result<Vec<Self>> {
+        serde_json::from_str(json)
+    }
+}
+
+impl CompoundColliderBuilder {
+    /// Serializes the compound into a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a collider contains values JSON can't represent, such as non-finite numbers.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserializes a compound from a JSON string created by [`CompoundColliderBuilder::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid JSON representation of a compound.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
@@ -1,35 +1,791 @@
-use bevy::prelude::*;
+use bevy::{
+    math::{
+        bounding::{Aabb2d, Aabb3d},
+        primitives::BoxedPolygon,
+        Affine3A,
+    },
+    prelude::*,
+};
+
+use crate::geometry;
 
 pub use builder::Builder as AbstractCollidersBuilder;
+pub use compound::CompoundColliderBuilder;
 
-use AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline};
+use AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline, Trimesh};
 
 #[cfg(feature = "avian2d")]
 mod avian2d;
+#[cfg(feature = "json")]
+mod json;
 #[cfg(feature = "rapier2d")]
 mod rapier2d;
+#[cfg(feature = "ron")]
+mod ron;
 
 mod builder;
+mod compound;
 
 /// An enumeration representing different types of abstract colliders.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AbstractCollider {
     Polyline(Vec<Vec2>),
     ConvexPolyline(Vec<Vec2>),
     ConvexHull(Vec<Vec2>),
     Heightfield(Vec<f32>, Vec2),
+    /// A triangle mesh of the polygon's points and the indices of its triangles.
+    Trimesh(Vec<Vec2>, Vec<[u32; 3]>),
+}
+
+/// The winding order of a polygon's points.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindingOrder {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A physics backend collider that can be created from an [`AbstractCollider`].
+///
+/// Implemented for the collider types of every enabled backend, so code can stay generic over them.
+pub trait BackendCollider: Sized + Send + Sync + 'static {
+    /// Converts the abstract collider into the backend collider, if the backend can represent it.
+    fn from_abstract(collider: AbstractCollider) -> Option<Self>;
 }
 
 impl AbstractCollider {
+    /// Creates a `Trimesh` collider by triangulating a closed polygon.
+    ///
+    /// If the polygon can't be triangulated, the collider is created without triangles
+    /// and can't be converted into a backend collider.
+    #[must_use]
+    pub fn trimesh(points: Vec<Vec2>) -> Self {
+        let indices = geometry::triangulate(&points).unwrap_or_default();
+        Trimesh(points, indices)
+    }
+
     /// Returns the points of the collider if applicable.
     ///
-    /// This method will return the points for `Polyline`, `ConvexPolyline`, and `ConvexHull`.
+    /// This method will return the points for `Polyline`, `ConvexPolyline`, `ConvexHull`, and `Trimesh`.
     /// For `Heightfield`, it returns `None` because it does not have a set of points in the same way.
     #[must_use]
     pub fn points(&self) -> Option<&Vec<Vec2>> {
         match self {
-            Polyline(points) | ConvexPolyline(points) | ConvexHull(points) => Some(points),
+            Polyline(points) | ConvexPolyline(points) | ConvexHull(points) | Trimesh(points, _) => {
+                Some(points)
+            }
             Heightfield(_, _) => None,
         }
     }
+
+    /// Converts the collider's polygon into Bevy's `BoxedPolygon` math primitive.
+    ///
+    /// This allows using the polygon with Bevy's math APIs, such as bounding volumes,
+    /// without depending on a shape rendering crate.
+    ///
+    /// # Returns
+    ///
+    /// The polygon, or `None` for `Heightfield`.
+    #[must_use]
+    pub fn to_polygon2d(&self) -> Option<BoxedPolygon> {
+        self.points().map(|points| points.iter().copied().collect())
+    }
+
+    /// Returns the axis-aligned bounding box of the collider's polygon.
+    ///
+    /// The box can be used with Bevy's bounding volumes for cheap broad-phase culling,
+    /// without spawning a physics collider.
+    ///
+    /// # Returns
+    ///
+    /// The bounding box, or `None` for `Heightfield` and empty polygons.
+    #[must_use]
+    pub fn aabb_2d(&self) -> Option<Aabb2d> {
+        let (min, max) = geometry::bounding_box(self.points()?)?;
+        Some(Aabb2d { min, max })
+    }
+
+    /// Returns the axis-aligned bounding box of the mesh created by [`AbstractCollider::extrude_z`].
+    ///
+    /// # Returns
+    ///
+    /// The bounding box, or `None` for `Heightfield` and empty polygons.
+    #[must_use]
+    pub fn aabb_3d(&self, depth: f32) -> Option<Aabb3d> {
+        let Aabb2d { min, max } = self.aabb_2d()?;
+        let depth = depth.abs() / 2.;
+        Some(Aabb3d::new(
+            ((min + max) / 2.).extend(0.),
+            ((max - min) / 2.).extend(depth),
+        ))
+    }
+
+    /// Checks whether a point lies inside the collider's polygon.
+    ///
+    /// The polygon is treated as closed, using the even-odd rule.
+    /// For `Heightfield`, it always returns `false` because it does not enclose an area.
+    #[must_use]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.points()
+            .is_some_and(|points| geometry::contains_point(points, point))
+    }
+
+    /// Returns the point on the edge of the collider's polygon that is closest to `point`.
+    ///
+    /// The polygon is treated as closed. For `Heightfield`, it always returns `None`.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the closest point and its distance to `point`, or `None` if the polygon is empty.
+    #[must_use]
+    pub fn closest_point(&self, point: Vec2) -> Option<(Vec2, f32)> {
+        geometry::closest_point(self.points()?, point)
+            .map(|closest| (closest, closest.distance(point)))
+    }
+
+    /// Samples the height of the collider's upper surface at a horizontal position.
+    ///
+    /// The height is interpolated linearly along the topmost segment of the polygon at `x`.
+    /// For `Heightfield`, it is interpolated between the heights, which span the width of its scale
+    /// centered at the origin.
+    ///
+    /// # Returns
+    ///
+    /// The y-coordinate of the surface, or `None` if `x` is outside of the collider.
+    #[must_use]
+    pub fn sample_at(&self, x: f32) -> Option<f32> {
+        self.surface_segment(x)
+            .map(|segment| geometry::y_at(segment, x))
+    }
+
+    /// Returns the slope (dy/dx) of the collider's upper surface at a horizontal position.
+    ///
+    /// The slope is the one of the topmost segment of the polygon at `x`, which doesn't depend on the
+    /// collider's scale. For `Heightfield`, it is the slope between the neighboring heights.
+    ///
+    /// # Returns
+    ///
+    /// The slope of the surface, or `None` if `x` is outside of the collider.
+    #[must_use]
+    pub fn gradient_at(&self, x: f32) -> Option<f32> {
+        self.surface_segment(x)
+            .map(|(left, right)| (right.y - left.y) / (right.x - left.x))
+    }
+
+    /// Checks whether the polygons of two colliders overlap.
+    ///
+    /// The bounding boxes are compared first as a fast reject, followed by a segment intersection test.
+    /// For `Heightfield`, it always returns `false`.
+    #[must_use]
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.points()
+            .zip(other.points())
+            .is_some_and(|(a, b)| geometry::intersects(a, b))
+    }
+
+    /// Returns the signed area of the polygon using the shoelace formula.
+    ///
+    /// The area is positive for counter-clockwise and negative for clockwise winding.
+    ///
+    /// # Returns
+    ///
+    /// The signed area, or `None` for `Heightfield`.
+    #[must_use]
+    pub fn signed_area(&self) -> Option<f32> {
+        self.points().map(|points| geometry::signed_area(points))
+    }
+
+    /// Returns the winding order of the polygon, derived from the sign of [`AbstractCollider::signed_area`].
+    ///
+    /// # Returns
+    ///
+    /// The winding order, or `None` for `Heightfield` and polygons without area.
+    #[must_use]
+    pub fn winding_order(&self) -> Option<WindingOrder> {
+        self.signed_area().filter(|area| *area != 0.).map(|area| {
+            if area > 0. {
+                WindingOrder::CounterClockwise
+            } else {
+                WindingOrder::Clockwise
+            }
+        })
+    }
+
+    /// Closes the polygon by appending a copy of its first point if the last point differs from it.
+    ///
+    /// The `polyline` constructors of rapier2d and avian2d only connect consecutive points,
+    /// so a `Polyline` has to be closed to include the segment from the last point back to the first.
+    /// For `Trimesh` and `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn close_loop(mut self) -> Self {
+        if let Polyline(points) | ConvexPolyline(points) | ConvexHull(points) = &mut self {
+            if let Some(&first) = points.first().filter(|first| points.last() != Some(first)) {
+                points.push(first);
+            }
+        }
+        self
+    }
+
+    /// Opens a closed polygon by removing its last point if it equals the first point.
+    ///
+    /// Generated colliders are open loops, which the `convex_polyline` constructors of rapier2d and avian2d
+    /// require, they fail for a closed `ConvexPolyline`.
+    /// For `Trimesh` and `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn open_loop(mut self) -> Self {
+        if let Polyline(points) | ConvexPolyline(points) | ConvexHull(points) = &mut self {
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+        }
+        self
+    }
+
+    /// Reverses the polygon's points if their winding order doesn't match `order`.
+    ///
+    /// A `Trimesh` is triangulated again. For `Heightfield` and polygons without area,
+    /// the collider is returned unchanged.
+    #[must_use]
+    pub fn with_winding_order(self, order: WindingOrder) -> Self {
+        if self.winding_order().is_some_and(|winding| winding != order) {
+            self.map_polygon(|mut points| {
+                points.reverse();
+                points
+            })
+        } else {
+            self
+        }
+    }
+
+    /// Returns the ratio of the polygon's area to the area of its convex hull.
+    ///
+    /// The ratio is `1.0` for convex polygons and lower the more concave the polygon is,
+    /// which helps choosing between convex and concave collider types.
+    ///
+    /// # Returns
+    ///
+    /// The convexity ratio, or `None` for `Heightfield` and polygons without area.
+    #[must_use]
+    pub fn convexity_ratio(&self) -> Option<f32> {
+        let points = self.points()?;
+        let hull_area = geometry::signed_area(&geometry::convex_hull(points));
+        (hull_area > 0.).then(|| geometry::signed_area(points).abs() / hull_area)
+    }
+
+    /// Returns the circularity of the polygon, `4π * area / perimeter²`.
+    ///
+    /// The circularity is `1.0` for a perfect circle and lower for elongated or jagged shapes,
+    /// which helps deciding whether a ball collider is a good fit.
+    ///
+    /// # Returns
+    ///
+    /// The circularity, or `None` for `Heightfield` and polygons without perimeter.
+    #[must_use]
+    pub fn circularity(&self) -> Option<f32> {
+        let points = self.points()?;
+        let perimeter = geometry::perimeter(points);
+        (perimeter > 0.).then(|| {
+            4. * std::f32::consts::PI * geometry::signed_area(points).abs() / perimeter.powi(2)
+        })
+    }
+
+    /// Returns the aspect ratio of the polygon's bounding box, its longer side divided by its shorter side.
+    ///
+    /// A high aspect ratio indicates an elongated shape that may fit a capsule collider.
+    ///
+    /// # Returns
+    ///
+    /// The aspect ratio, or `None` for `Heightfield` and polygons with an empty bounding box.
+    #[must_use]
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        let (min, max) = geometry::bounding_box(self.points()?)?;
+        let size = max - min;
+        (size.min_element() > 0.).then(|| size.max_element() / size.min_element())
+    }
+
+    /// Extrudes the collider's polygon along the z axis into a closed 3D triangle mesh.
+    ///
+    /// The mesh consists of a front face at `z = depth / 2`, a back face at `z = -depth / 2` and the side walls
+    /// between them, with all triangles facing outwards. It is suitable for the `trimesh` and `convex_hull`
+    /// constructors of 3D physics backends, such as rapier3d and avian3d.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the vertices and the indices of the triangles, or `None` for `Heightfield`
+    /// and polygons that can't be triangulated.
+    #[must_use]
+    pub fn extrude_z(&self, depth: f32) -> Option<(Vec<Vec3>, Vec<[u32; 3]>)> {
+        let mut points = self.points()?.clone();
+        if geometry::signed_area(&points) < 0. {
+            points.reverse();
+        }
+        let faces = geometry::triangulate(&points)?;
+        let len = u32::try_from(points.len()).ok()?;
+
+        let (front, back) = (depth / 2., -depth / 2.);
+        let vertices: Vec<Vec3> = points
+            .iter()
+            .map(|point| point.extend(front))
+            .chain(points.iter().map(|point| point.extend(back)))
+            .collect();
+        let indices = faces
+            .iter()
+            .copied()
+            .chain(faces.iter().map(|&[a, b, c]| [a + len, c + len, b + len]))
+            .chain((0..len).flat_map(|a| {
+                let b = (a + 1) % len;
+                [[a + len, b + len, b], [a + len, b, a]]
+            }))
+            .collect();
+        Some((vertices, indices))
+    }
+
+    /// Partitions the collider's polygon into convex pieces with the Hertel-Mehlhorn algorithm.
+    ///
+    /// Every piece can become a `ConvexPolyline` collider, and together they can be assembled
+    /// with a [`CompoundColliderBuilder`] into an exact collider for a concave shape.
+    ///
+    /// # Returns
+    ///
+    /// The points of the convex pieces in counter-clockwise order, or `None` for `Heightfield`
+    /// and polygons that can't be triangulated.
+    #[must_use]
+    pub fn to_convex_partition(&self) -> Option<Vec<Vec<Vec2>>> {
+        geometry::convex_partition(self.points()?)
+    }
+
+    /// Smooths the collider's polygon with Chaikin's corner cutting algorithm.
+    ///
+    /// Every iteration replaces each segment of the closed polygon with two points at 1/4 and 3/4
+    /// of its length, doubling the number of points. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn smooth(self, iterations: u32) -> Self {
+        self.map_polygon(|points| geometry::chaikin(points, iterations))
+    }
+
+    /// Subdivides every segment of the collider's polygon into `factor` segments of equal length.
+    ///
+    /// This adds points before simplifying the polygon, for example with [`AbstractCollider::approximate`],
+    /// which can then choose from more candidates. A factor of `1` returns the polygon unchanged
+    /// and a factor of `4` quadruples the number of points. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn subdivide(self, factor: u32) -> Self {
+        self.map_polygon(|points| geometry::subdivide(&points, factor))
+    }
+
+    /// Removes the points of the collider's polygon that are collinear with their neighbors.
+    ///
+    /// A point is removed if the cross product of the segments to and from it is at most `tolerance`,
+    /// so unlike [`AbstractCollider::approximate`] the shape of the polygon is kept, only redundant
+    /// points on straight edges are dropped. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn remove_collinear_points(self, tolerance: f32) -> Self {
+        self.map_polygon(|points| geometry::remove_collinear(points, tolerance))
+    }
+
+    /// Approximates the collider's polygon with at most `max_vertices` points.
+    ///
+    /// Points are removed with the Visvalingam-Whyatt algorithm, which drops the point contributing
+    /// the least area first, giving predictable collider complexity for LOD systems. At least three points
+    /// are kept, and for aggressive budgets the result may not match the original contour closely.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn approximate(self, max_vertices: usize) -> Self {
+        self.map_polygon(|points| geometry::visvalingam(points, max_vertices))
+    }
+
+    /// Simplifies the collider's polygon to exactly `n` points.
+    ///
+    /// Points are removed with the Ramer-Douglas-Peucker algorithm, whose epsilon is found with a binary search,
+    /// so no knowledge of suitable epsilon values is needed. If no epsilon gives exactly `n` points, the last points
+    /// are removed with the Visvalingam-Whyatt algorithm. At least three points are kept.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn simplify_to_n_points(self, n: usize) -> Self {
+        self.map_polygon(|points| geometry::douglas_peucker_to(points, n))
+    }
+
+    /// Merges consecutive points of the collider's polygon that are at most `threshold` apart.
+    ///
+    /// Points that should be identical may differ slightly after translation and scaling, welding them
+    /// prevents zero-length segments from reaching the physics backend. Each run of nearby points is
+    /// replaced by its first point, including the points at the end of the loop that are near its first point.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn weld_vertices(self, threshold: f32) -> Self {
+        self.map_polygon(|points| geometry::weld(points, threshold))
+    }
+
+    /// Scales the collider by the given factor.
+    ///
+    /// For `Heightfield`, the factor is applied to its scale instead of the heights.
+    #[must_use]
+    pub fn scale(mut self, factor: Vec2) -> Self {
+        match &mut self {
+            Polyline(points) | ConvexPolyline(points) | ConvexHull(points) | Trimesh(points, _) => {
+                for point in points {
+                    *point *= factor;
+                }
+            }
+            Heightfield(_, scale) => *scale *= factor,
+        }
+        self
+    }
+
+    /// Maps the collider's polygon into the unit square, so its bounding box spans `[0, 1]` on both axes.
+    ///
+    /// Normalized colliders can be stored compactly, compared across images of different sizes
+    /// and reused with different world scales through [`AbstractCollider::denormalize`].
+    /// Each axis is scaled separately, an axis without extent is mapped to `0`.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self.map_polygon(|points| {
+            let Some((min, max)) = geometry::bounding_box(&points) else {
+                return points;
+            };
+            let size = (max - min).max(Vec2::splat(f32::EPSILON));
+            points
+                .into_iter()
+                .map(|point| (point - min) / size)
+                .collect()
+        })
+    }
+
+    /// Scales a collider created by [`AbstractCollider::normalize`] back to a bounding box of `width` × `height`.
+    ///
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn denormalize(self, width: f32, height: f32) -> Self {
+        let size = Vec2::new(width, height);
+        self.map_points(|point| point * size)
+    }
+
+    /// Rotates the collider's polygon counter-clockwise around the centroid of its points.
+    ///
+    /// The centroid is the average of the points, so the collider keeps its position and the rotation composes
+    /// with [`AbstractCollider::scale`] regardless of the anchor. Use [`AbstractCollider::rotate_90`] and its
+    /// siblings to rotate exactly around the origin. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn rotate(self, angle: f32) -> Self {
+        let rotation = Vec2::from_angle(angle);
+        self.map_polygon(|points| {
+            let centroid = points.iter().sum::<Vec2>() / points.len().max(1) as f32;
+            points
+                .into_iter()
+                .map(|point| centroid + rotation.rotate(point - centroid))
+                .collect()
+        })
+    }
+
+    /// Flips the collider's polygon vertically by negating the y-coordinate of every point.
+    ///
+    /// Generated colliders already use Bevy's y-up coordinate system, this is needed for colliders
+    /// created from other sources with y pointing down, such as points from an external editor.
+    /// Flipping reverses the winding order. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn flip_y(self) -> Self {
+        self.map_points(|point| Vec2::new(point.x, -point.y))
+    }
+
+    /// Mirrors the collider horizontally across the vertical axis through the origin.
+    ///
+    /// With a centered anchor, the origin is the center of the image. Flipping reverses the winding order,
+    /// combined with [`AbstractCollider::flip_y`] it gives a full reflection. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the order of the heights is reversed.
+    #[must_use]
+    pub fn flip_x(self) -> Self {
+        match self {
+            Heightfield(mut heights, scale) => {
+                heights.reverse();
+                Heightfield(heights, scale)
+            }
+            collider => collider.map_points(|point| Vec2::new(-point.x, point.y)),
+        }
+    }
+
+    /// Rotates the collider's polygon by 90 degrees counter-clockwise around the origin.
+    ///
+    /// With a centered anchor, the origin is the center of the image. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn rotate_90(self) -> Self {
+        self.map_points(Vec2::perp)
+    }
+
+    /// Rotates the collider's polygon by 180 degrees around the origin.
+    ///
+    /// With a centered anchor, the origin is the center of the image. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn rotate_180(self) -> Self {
+        self.map_points(|point| -point)
+    }
+
+    /// Rotates the collider's polygon by 270 degrees counter-clockwise around the origin.
+    ///
+    /// With a centered anchor, the origin is the center of the image. A `Trimesh` is triangulated again.
+    /// For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn rotate_270(self) -> Self {
+        self.map_points(|point| -point.perp())
+    }
+
+    /// Flips the collider's polygon vertically within an image, mapping every y-coordinate to `image_height - 1 - y`.
+    ///
+    /// This converts points in image pixel coordinates between y pointing down and y pointing up,
+    /// while keeping them within the bounds of the image. Flipping reverses the winding order.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, the collider is returned unchanged.
+    #[must_use]
+    pub fn flip_y_pixels(self, image_height: u32) -> Self {
+        let top = image_height.saturating_sub(1) as f32;
+        self.map_points(|point| Vec2::new(point.x, top - point.y))
+    }
+
+    /// Applies the translation, rotation and scale of a Bevy `Transform` to the collider's polygon.
+    ///
+    /// Only the x and y components of the translation and scale, and the rotation around the z axis,
+    /// affect the points. A `Trimesh` is triangulated again. For `Heightfield`, only the scale is applied
+    /// to its scale, like [`AbstractCollider::scale`].
+    #[must_use]
+    pub fn transform(self, transform: &Transform) -> Self {
+        self.map_affine(transform.compute_affine(), transform.scale.truncate())
+    }
+
+    /// Converts the collider into world space with the `GlobalTransform` of its entity.
+    ///
+    /// This is useful for debug rendering and spatial queries of entities at arbitrary positions.
+    /// A `Trimesh` is triangulated again. For `Heightfield`, only the scale is applied to its scale.
+    #[must_use]
+    pub fn to_world_space(&self, transform: &GlobalTransform) -> Self {
+        self.clone()
+            .map_affine(transform.affine(), transform.scale().truncate())
+    }
+
+    /// Splits the collider's polygon into two colliders along a line.
+    ///
+    /// The first collider is the part on the left of the line through `origin` in the direction
+    /// of `axis`, the second collider is the part on the right. Segments crossing the line are cut,
+    /// so both parts share their points on the line and their areas sum to the original area.
+    /// A part is empty if the polygon lies entirely on the other side. Each `Trimesh` is triangulated again.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of both colliders, or `None` for `Heightfield`.
+    #[must_use]
+    pub fn split_by_axis(self, axis: Vec2, origin: Vec2) -> Option<(Self, Self)> {
+        self.points()?;
+        let right = self
+            .clone()
+            .map_polygon(|points| geometry::clip_left(&points, -axis, origin));
+        let left = self.map_polygon(|points| geometry::clip_left(&points, axis, origin));
+        Some((left, right))
+    }
+
+    /// Returns the topmost segment of the collider's surface at the horizontal position `x`, from left to right.
+    fn surface_segment(&self, x: f32) -> Option<(Vec2, Vec2)> {
+        match self {
+            Heightfield(heights, scale) => {
+                let columns = heights
+                    .len()
+                    .checked_sub(1)
+                    .filter(|columns| *columns > 0)?;
+                let step = scale.x / columns as f32;
+                let position = (x + scale.x / 2.) / step;
+                if !(0. ..=columns as f32).contains(&position) {
+                    return None;
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let column = (position as usize).min(columns - 1);
+                let point = |column: usize| {
+                    Vec2::new(
+                        column as f32 * step - scale.x / 2.,
+                        heights[column] * scale.y,
+                    )
+                };
+                Some((point(column), point(column + 1)))
+            }
+            collider => geometry::topmost_segment(collider.points()?, x),
+        }
+    }
+
+    /// Applies an affine transform to the collider's points, or `scale` to the scale of a `Heightfield`.
+    fn map_affine(self, affine: Affine3A, scale: Vec2) -> Self {
+        match self {
+            Heightfield(heights, heightfield_scale) => {
+                Heightfield(heights, heightfield_scale * scale)
+            }
+            collider => {
+                collider.map_points(|point| affine.transform_point3(point.extend(0.)).truncate())
+            }
+        }
+    }
+
+    /// Replaces every point of the collider's polygon with the result of `f`, triangulating a `Trimesh` again.
+    fn map_points(self, f: impl Fn(Vec2) -> Vec2) -> Self {
+        self.map_polygon(|points| points.into_iter().map(f).collect())
+    }
+
+    /// Replaces the collider's polygon with the result of `f`, triangulating a `Trimesh` again.
+    fn map_polygon(self, f: impl FnOnce(Vec<Vec2>) -> Vec<Vec2>) -> Self {
+        match self {
+            Polyline(points) => Polyline(f(points)),
+            ConvexPolyline(points) => ConvexPolyline(f(points)),
+            ConvexHull(points) => ConvexHull(f(points)),
+            Trimesh(points, _) => Self::trimesh(f(points)),
+            heightfield @ Heightfield(_, _) => heightfield,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gradient_of_scaled_slope() {
+        // A right triangle whose hypotenuse rises at 45° from left to right.
+        let triangle = ConvexPolyline(vec![
+            Vec2::new(-4., -4.),
+            Vec2::new(4., -4.),
+            Vec2::new(4., 4.),
+        ]);
+        for pixels_per_unit in [1., 100.] {
+            let collider = triangle.clone().scale(Vec2::splat(1. / pixels_per_unit));
+            let gradient = collider.gradient_at(1. / pixels_per_unit).unwrap();
+            assert!(
+                (gradient - 1.).abs() < 1e-4,
+                "{pixels_per_unit}: {gradient}"
+            );
+        }
+    }
+
+    #[test]
+    fn gradient_of_heightfield() {
+        let collider = Heightfield(vec![0., 1., 1.], Vec2::new(0.02, 0.01));
+        assert!((collider.gradient_at(-0.005).unwrap() - 1.).abs() < 1e-4);
+        assert_eq!(collider.gradient_at(0.005), Some(0.));
+        assert_eq!(collider.gradient_at(0.02), None);
+    }
+
+    #[test]
+    fn split_halves_sum_to_the_original_area() {
+        let square = ConvexPolyline(vec![
+            Vec2::new(0., 0.),
+            Vec2::new(4., 0.),
+            Vec2::new(4., 4.),
+            Vec2::new(0., 4.),
+        ]);
+        // The line x = 1 pointing down, so its left side is x >= 1.
+        let (left, right) = square.split_by_axis(Vec2::NEG_Y, Vec2::X).unwrap();
+        assert_eq!(left.signed_area(), Some(12.));
+        assert_eq!(right.signed_area(), Some(4.));
+        assert!(left.points().unwrap().iter().all(|point| point.x >= 1.));
+        assert!(right.points().unwrap().iter().all(|point| point.x <= 1.));
+    }
+
+    #[test]
+    fn split_outside_and_heightfield() {
+        let triangle = ConvexHull(vec![Vec2::ZERO, Vec2::X, Vec2::Y]);
+        let (left, right) = triangle
+            .clone()
+            .split_by_axis(Vec2::Y, Vec2::splat(5.))
+            .unwrap();
+        assert_eq!(left, triangle);
+        assert_eq!(right.points(), Some(&Vec::new()));
+        assert!(Heightfield(vec![0., 1.], Vec2::ONE)
+            .split_by_axis(Vec2::Y, Vec2::ZERO)
+            .is_none());
+    }
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0., 0.),
+            Vec2::new(2., 0.),
+            Vec2::new(2., 2.),
+            Vec2::new(0., 2.),
+        ]
+    }
+
+    #[test]
+    fn winding_order_of_square() {
+        let counter_clockwise = ConvexPolyline(square());
+        let clockwise = ConvexPolyline(square().into_iter().rev().collect());
+        assert_eq!(
+            counter_clockwise.winding_order(),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert_eq!(clockwise.winding_order(), Some(WindingOrder::Clockwise));
+        assert_eq!(
+            clockwise
+                .with_winding_order(WindingOrder::CounterClockwise)
+                .winding_order(),
+            Some(WindingOrder::CounterClockwise)
+        );
+        assert_eq!(Polyline(vec![Vec2::ZERO, Vec2::X]).winding_order(), None);
+    }
+
+    #[test]
+    fn shape_metrics() {
+        let circle: Vec<Vec2> = (0..1000)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / 1000.))
+            .collect();
+        let circularity = ConvexPolyline(circle).circularity().unwrap();
+        assert!((circularity - 1.).abs() < 1e-3, "{circularity}");
+        let circularity = ConvexPolyline(square()).circularity().unwrap();
+        assert!(
+            (circularity - std::f32::consts::FRAC_PI_4).abs() < 1e-5,
+            "{circularity}"
+        );
+
+        let l_shape = Polyline(vec![
+            Vec2::new(0., 0.),
+            Vec2::new(2., 0.),
+            Vec2::new(2., 1.),
+            Vec2::new(1., 1.),
+            Vec2::new(1., 2.),
+            Vec2::new(0., 2.),
+        ]);
+        assert_eq!(l_shape.convexity_ratio(), Some(3. / 3.5));
+        assert_eq!(ConvexPolyline(square()).convexity_ratio(), Some(1.));
+        let rect = ConvexPolyline(square()).scale(Vec2::new(2., 1.));
+        assert_eq!(rect.aspect_ratio(), Some(2.));
+    }
+
+    #[test]
+    fn extrude_square() {
+        for points in [square(), square().into_iter().rev().collect()] {
+            let (vertices, indices) = ConvexPolyline(points).extrude_z(3.).unwrap();
+            assert_eq!(vertices.len(), 8);
+            // Two triangles for each of the front and back faces and for each of the four side walls.
+            assert_eq!(indices.len(), 12);
+            assert!(indices.iter().flatten().all(|&index| index < 8));
+            // The signed volume is only positive if all triangles face outwards.
+            let volume: f32 = indices
+                .iter()
+                .map(|&[a, b, c]| {
+                    let [a, b, c] = [a, b, c].map(|index| vertices[index as usize]);
+                    a.dot(b.cross(c)) / 6.
+                })
+                .sum();
+            assert!((volume - 12.).abs() < 1e-4);
+        }
+        assert!(Heightfield(vec![1.], Vec2::ONE).extrude_z(1.).is_none());
+    }
+
+    #[test]
+    fn simplify_square_to_n_points() {
+        let simplified = ConvexPolyline(square()).simplify_to_n_points(3);
+        assert_eq!(simplified.points().map(Vec::len), Some(3));
+        let unchanged = ConvexPolyline(square()).simplify_to_n_points(8);
+        assert_eq!(unchanged, ConvexPolyline(square()));
+        let heightfield = Heightfield(vec![1., 2.], Vec2::ONE);
+        assert_eq!(heightfield.clone().simplify_to_n_points(3), heightfield);
+    }
 }
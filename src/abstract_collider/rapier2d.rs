@@ -1,13 +1,112 @@
-use bevy_rapier2d::prelude::Collider;
+use bevy_rapier2d::prelude::{Collider, ColliderMassProperties, MassProperties};
+use edges::binary_image::Bit;
+use image::GenericImageView;
 
-use super::AbstractCollider;
-use super::AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline};
+use super::AbstractCollider::{ConvexHull, ConvexPolyline, Heightfield, Polyline, Trimesh};
+use super::{AbstractCollider, AbstractCollidersBuilder, BackendCollider, CompoundColliderBuilder};
+use crate::geometry;
 
 impl AbstractCollider {
     #[must_use]
     pub fn to_rapier(self) -> Option<Collider> {
         self.into()
     }
+
+    /// Extracts the points of a rapier2d collider.
+    ///
+    /// A polyline becomes a `Polyline`, and a convex polygon, as created from both `ConvexPolyline`
+    /// and `ConvexHull`, becomes a `ConvexPolyline`.
+    ///
+    /// # Returns
+    ///
+    /// The abstract collider, or `None` for any other shape, including heightfields and triangle meshes.
+    #[must_use]
+    pub fn from_rapier(collider: &Collider) -> Option<Self> {
+        collider
+            .as_polyline()
+            .map(|polyline| Polyline(polyline.vertices().collect()))
+            .or_else(|| {
+                collider
+                    .as_convex_polygon()
+                    .map(|polygon| ConvexPolyline(polygon.points().collect()))
+            })
+    }
+
+    /// Computes the rapier2d mass properties of the collider's polygon for a uniform density.
+    ///
+    /// The mass, center of mass and moment of inertia are derived from the area enclosed by the polygon,
+    /// for concave shapes this matches the filled outline rather than the shape of a `Polyline` collider.
+    ///
+    /// # Returns
+    ///
+    /// The mass properties, or `None` for `Heightfield` and polygons without area.
+    #[must_use]
+    pub fn rapier_mass_properties(&self, density: f32) -> Option<ColliderMassProperties> {
+        let (area, center, inertia) = geometry::mass_properties(self.points()?)?;
+        Some(ColliderMassProperties::MassProperties(MassProperties {
+            local_center_of_mass: center,
+            mass: area * density,
+            principal_inertia: inertia * density,
+        }))
+    }
+}
+
+impl<I: GenericImageView<Pixel = Bit>> AbstractCollidersBuilder<I> {
+    /// Generates a single rapier2d collider based on the current builder's settings.
+    ///
+    /// # Returns
+    ///
+    /// The collider, or `None` if no object was found or rapier2d can't represent it.
+    #[must_use]
+    pub fn build_rapier(&self) -> Option<Collider> {
+        self.build()
+    }
+
+    /// Generates rapier2d colliders with their mass properties for a uniform density,
+    /// ready to be inserted into an entity together.
+    ///
+    /// The mass properties are computed with [`AbstractCollider::rapier_mass_properties`]
+    /// from the scaled colliders returned by [`AbstractCollidersBuilder::multiple`].
+    ///
+    /// # Returns
+    ///
+    /// The pairs of collider and mass properties, skipping objects rapier2d can't represent
+    /// and objects without area, such as `Heightfield` colliders.
+    #[must_use]
+    pub fn multiple_with_mass_properties(
+        &self,
+        density: f32,
+    ) -> Vec<(Collider, ColliderMassProperties)> {
+        self.multiple()
+            .into_iter()
+            .filter_map(|collider| with_mass_properties(collider, density))
+            .collect()
+    }
+
+    /// Generates a single rapier2d collider with its mass properties for a uniform density.
+    ///
+    /// See [`AbstractCollidersBuilder::multiple_with_mass_properties`].
+    ///
+    /// # Returns
+    ///
+    /// The collider and its mass properties, or `None` if no object was found, rapier2d can't represent it
+    /// or it has no area.
+    #[must_use]
+    pub fn single_with_mass_properties(
+        &self,
+        density: f32,
+    ) -> Option<(Collider, ColliderMassProperties)> {
+        self.single()
+            .and_then(|collider| with_mass_properties(collider, density))
+    }
+}
+
+fn with_mass_properties(
+    collider: AbstractCollider,
+    density: f32,
+) -> Option<(Collider, ColliderMassProperties)> {
+    let mass_properties = collider.rapier_mass_properties(density)?;
+    Some((collider.to_rapier()?, mass_properties))
 }
 
 impl From<AbstractCollider> for Option<Collider> {
@@ -17,6 +116,81 @@ impl From<AbstractCollider> for Option<Collider> {
             ConvexPolyline(points) => Collider::convex_polyline(points),
             ConvexHull(points) => Collider::convex_hull(&points),
             Heightfield(heights, scale) => Some(Collider::heightfield(heights, scale)),
+            Trimesh(vertices, indices) => {
+                (!indices.is_empty()).then(|| Collider::trimesh(vertices, indices))
+            }
+        }
+    }
+}
+
+impl BackendCollider for Collider {
+    fn from_abstract(collider: AbstractCollider) -> Option<Self> {
+        collider.into()
+    }
+}
+
+impl CompoundColliderBuilder {
+    /// Converts the compound into a single compound collider.
+    ///
+    /// Composite shapes can't be nested in a compound, so this fails for `Polyline` and `Trimesh` colliders,
+    /// use [`AbstractCollider::to_convex_partition`] to split them into convex parts first.
+    ///
+    /// # Returns
+    ///
+    /// The compound collider, or `None` if the compound is empty or a collider can't be converted or nested.
+    #[must_use]
+    pub fn to_rapier(self) -> Option<Collider> {
+        self.into()
+    }
+}
+
+impl From<CompoundColliderBuilder> for Option<Collider> {
+    fn from(value: CompoundColliderBuilder) -> Self {
+        let shapes = value
+            .colliders()
+            .iter()
+            .map(|(offset, collider)| {
+                collider
+                    .clone()
+                    .to_rapier()
+                    .filter(|c| c.raw.as_composite_shape().is_none())
+                    .map(|c| (*offset, 0., c))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        (!shapes.is_empty()).then(|| Collider::compound(shapes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abstract_collider::compound::tests::{composite_compounds, convex_compound};
+
+    #[test]
+    fn mass_properties_of_square() {
+        // The outline through the pixel centers of a 4×4 block is a 3×3 square centered at the origin.
+        let cells = [b'#'; 16];
+        let builder = AbstractCollidersBuilder::from_int_grid(&cells, 4, 4, b"#").unwrap();
+        let (_, mass_properties) = builder.single_with_mass_properties(2.).unwrap();
+        let ColliderMassProperties::MassProperties(mass_properties) = mass_properties else {
+            panic!("{mass_properties:?}");
+        };
+        // mass = density * area, inertia = mass * (width² + height²) / 12
+        assert!((mass_properties.mass - 18.).abs() < 1e-4);
+        assert!(mass_properties.local_center_of_mass.length() < 1e-4);
+        assert!((mass_properties.principal_inertia - 27.).abs() < 1e-3);
+        assert_eq!(builder.multiple_with_mass_properties(2.).len(), 1);
+    }
+
+    #[test]
+    fn compound_of_convex_colliders() {
+        assert!(convex_compound().to_rapier().is_some());
+    }
+
+    #[test]
+    fn compound_rejects_composite_colliders() {
+        for compound in composite_compounds() {
+            assert!(compound.to_rapier().is_none());
         }
     }
 }
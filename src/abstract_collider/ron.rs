@@ -0,0 +1,59 @@
+use super::{AbstractCollider, CompoundColliderBuilder};
+
+impl AbstractCollider {
+    /// Serializes the collider into a RON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collider can't be represented in RON.
+    pub fn to_ron(&self) -> ron::Result<String> {
+        ron::to_string(self)
+    }
+
+    /// Deserializes a collider from a RON string created by [`AbstractCollider::to_ron`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid RON representation of a collider.
+    pub fn from_ron(ron: &str) -> ron::error::SpannedResult<Self> {
+        ron::from_str(ron)
+    }
+
+    /// Serializes a list of colliders into a RON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a collider can't be represented in RON.
+    pub fn multiple_to_ron(colliders: &[Self]) -> ron::Result<String> {
+        ron::to_string(colliders)
+    }
+
+    /// Deserializes a list of colliders from a RON string, as written by the CLI.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid RON list of colliders.
+    pub fn multiple_from_ron(ron: &str) -> ron::error::SpannedResult<Vec<Self>> {
+        ron::from_str(ron)
+    }
+}
+
+impl CompoundColliderBuilder {
+    /// Serializes the compound into a RON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a collider can't be represented in RON.
+    pub fn to_ron(&self) -> ron::Result<String> {
+        ron::to_string(self)
+    }
+
+    /// Deserializes a compound from a RON string created by [`CompoundColliderBuilder::to_ron`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't a valid RON representation of a compound.
+    pub fn from_ron(ron: &str) -> ron::error::SpannedResult<Self> {
+        ron::from_str(ron)
+    }
+}
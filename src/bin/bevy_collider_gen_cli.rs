@@ -0,0 +1,138 @@
+//! Bakes colliders from an image into a RON or JSON file.
+//!
+//! ```sh
+//! bevy_collider_gen_cli --input sprites/car.png --type convex_polyline --output assets/colliders/car.collider.ron
+//! ```
+
+use std::{fs, process::ExitCode};
+
+use bevy_collider_gen::prelude::*;
+
+const USAGE: &str = "\
+usage: bevy_collider_gen_cli --input <image> [--type <type>] [--format <format>] [--output <file>]
+
+options:
+  --input <image>     the image to generate colliders from
+  --type <type>       polyline (default), convex_polyline, convex_hull, heightfield, trimesh
+                      or pixel_perfect
+  --format <format>   ron (default) or json
+  --output <file>     the file to write the colliders to, stdout if omitted";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Ron,
+    Json,
+}
+
+struct Args {
+    input: String,
+    collider_type: ColliderType,
+    format: Format,
+    output: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut input = None;
+    let mut collider_type = ColliderType::default();
+    let mut format = Format::Ron;
+    let mut output = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for `{arg}`"))
+        };
+        match arg.as_str() {
+            "--input" => input = Some(value()?),
+            "--type" => {
+                collider_type = match value()?.as_str() {
+                    "polyline" => ColliderType::Polyline,
+                    "convex_polyline" => ColliderType::ConvexPolyline,
+                    "convex_hull" => ColliderType::ConvexHull,
+                    "heightfield" => ColliderType::Heightfield,
+                    "trimesh" => ColliderType::Trimesh,
+                    "pixel_perfect" => ColliderType::PixelPerfect,
+                    other => return Err(format!("unknown collider type `{other}`")),
+                }
+            }
+            "--format" => {
+                format = match value()?.as_str() {
+                    "ron" => Format::Ron,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format `{other}`")),
+                }
+            }
+            "--output" => output = Some(value()?),
+            other => return Err(format!("unknown argument `{other}`")),
+        }
+    }
+
+    Ok(Args {
+        input: input.ok_or("missing `--input`")?,
+        collider_type,
+        format,
+        output,
+    })
+}
+
+/// Serializes colliders into a list that `AbstractCollider::multiple_from_ron`
+/// or `AbstractCollider::multiple_from_json` loads.
+fn serialize(colliders: &[AbstractCollider], format: Format) -> Result<String, String> {
+    match format {
+        Format::Ron => ron::ser::to_string_pretty(colliders, ron::ser::PrettyConfig::default())
+            .map_err(|err| err.to_string()),
+        Format::Json => serde_json::to_string_pretty(colliders).map_err(|err| err.to_string()),
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let image = image::open(&args.input).map_err(|err| format!("{}: {err}", args.input))?;
+    let colliders = EdgesBuilder::new()
+        .dynamic_image(&image)
+        .with_type(args.collider_type)
+        .multiple();
+
+    let serialized = serialize(&colliders, args.format)?;
+
+    if let Some(output) = &args.output {
+        fs::write(output, serialized).map_err(|err| format!("{output}: {err}"))
+    } else {
+        println!("{serialized}");
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    let result = parse_args()
+        .map_err(|err| format!("{err}\n\n{USAGE}"))
+        .and_then(|args| run(&args));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::Vec2;
+
+    use super::*;
+
+    #[test]
+    fn output_round_trip() {
+        let colliders = vec![
+            AbstractCollider::ConvexPolyline(vec![Vec2::ZERO, Vec2::X, Vec2::Y]),
+            AbstractCollider::Heightfield(vec![0., 1., 0.5], Vec2::new(2., 1.)),
+        ];
+        let ron = serialize(&colliders, Format::Ron).unwrap();
+        let loaded = AbstractCollider::multiple_from_ron(&ron).unwrap();
+        assert_eq!(loaded, colliders);
+        let json = serialize(&colliders, Format::Json).unwrap();
+        let loaded = AbstractCollider::multiple_from_json(&json).unwrap();
+        assert_eq!(loaded, colliders);
+    }
+}
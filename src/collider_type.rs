@@ -1,9 +1,24 @@
 /// An enumeration representing the different types of colliders that can be created.
-#[derive(Clone, Copy, Debug, Default, Hash)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColliderType {
     #[default]
     Polyline,
     ConvexPolyline,
     ConvexHull,
     Heightfield,
+    /// A heightfield with exactly `columns` heights, sampled at evenly spaced positions across the object.
+    ///
+    /// Unlike `Heightfield`, which has one height per pixel of the object's width, the number of heights
+    /// doesn't depend on the image's resolution.
+    HeightfieldN {
+        columns: usize,
+    },
+    Trimesh,
+    /// A triangle mesh with two triangles per opaque pixel, for small sprites where every pixel matters.
+    ///
+    /// Images larger than 64×64 pixels don't generate colliders, to avoid accidentally huge meshes,
+    /// and log a warning instead.
+    /// The vertex budget and winding order settings of the builder don't apply.
+    PixelPerfect,
 }
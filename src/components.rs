@@ -0,0 +1,314 @@
+use bevy::prelude::*;
+use edges::{
+    binary_image::{BinaryImage, Bit},
+    EdgesIter,
+};
+use image::GenericImageView;
+
+use crate::utils::from_pixels;
+
+/// The algorithm used to separate the opaque pixels of an image into objects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectedComponentsAlgo {
+    /// Labels each object by flooding it from its first pixel, simple but slower for large images.
+    FloodFill,
+    /// Labels all objects in two passes over the image using union-find.
+    #[default]
+    TwoPassUnionFind,
+    /// Traces edges directly without labeling, objects inside the holes of other objects are ignored.
+    ContourTracing,
+}
+
+/// Separates the opaque pixels of a binary image into 8-connected components.
+///
+/// Each component is returned as the offset of its bounding box in the image together with
+/// a `BinaryImage` of the bounding box that only contains the pixels of that component.
+/// Components are ordered by their topmost row, and by their rightmost pixel within that row,
+/// which is the order in which the edges of objects are traced.
+pub fn components<I>(image: &I, algorithm: ConnectedComponentsAlgo) -> Vec<(UVec2, BinaryImage)>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let index = |x: u32, y: u32| y as usize * width as usize + x as usize;
+
+    let (mut labels, mut parents) = match algorithm {
+        ConnectedComponentsAlgo::FloodFill => flood_fill(image),
+        ConnectedComponentsAlgo::TwoPassUnionFind | ConnectedComponentsAlgo::ContourTracing => {
+            two_pass(image)
+        }
+    };
+
+    let mut ids = vec![usize::MAX; parents.len()];
+    let mut bounds: Vec<(UVec2, UVec2)> = Vec::new();
+    for y in 0..height {
+        for x in (0..width).rev() {
+            let label = labels[index(x, y)];
+            if label == 0 {
+                continue;
+            }
+            let root = find(&mut parents, label);
+            if ids[root] == usize::MAX {
+                ids[root] = bounds.len();
+                bounds.push((UVec2::new(x, y), UVec2::new(x, y)));
+            }
+            labels[index(x, y)] = ids[root] + 1;
+            let (min, max) = &mut bounds[ids[root]];
+            *min = min.min(UVec2::new(x, y));
+            *max = max.max(UVec2::new(x, y));
+        }
+    }
+
+    bounds
+        .into_iter()
+        .enumerate()
+        .map(|(id, (min, max))| {
+            let size = max - min + UVec2::ONE;
+            let pixels: Vec<u8> = (min.y..=max.y)
+                .flat_map(|y| (min.x..=max.x).map(move |x| (x, y)))
+                .map(|(x, y)| u8::from(labels[index(x, y)] == id + 1))
+                .collect();
+            (min, from_pixels(size.x, size.y, &pixels))
+        })
+        .collect()
+}
+
+/// Counts the 8-connected components of a binary image without separating them into images.
+pub fn count<I>(image: &I, algorithm: ConnectedComponentsAlgo) -> usize
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (_, mut parents) = match algorithm {
+        ConnectedComponentsAlgo::FloodFill => flood_fill(image),
+        ConnectedComponentsAlgo::TwoPassUnionFind | ConnectedComponentsAlgo::ContourTracing => {
+            two_pass(image)
+        }
+    };
+    (1..parents.len())
+        .filter(|&label| find(&mut parents, label) == label)
+        .count()
+}
+
+/// Traces the outer edge of a component returned by [`components`], in image pixel coordinates.
+pub fn outline((offset, image): &(UVec2, BinaryImage)) -> Option<Vec<UVec2>> {
+    EdgesIter::new(image)
+        .next()
+        .map(|polygon| polygon.into_iter().map(|point| point + *offset).collect())
+}
+
+/// Labels pixels in two passes, merging labels of touching pixels with union-find.
+///
+/// Returns the label of every pixel, `0` for transparent pixels, and the parent of every label.
+fn two_pass<I>(image: &I) -> (Vec<usize>, Vec<usize>)
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let index = |x: u32, y: u32| y as usize * width as usize + x as usize;
+
+    let mut labels = vec![0usize; width as usize * height as usize];
+    let mut parents = vec![0usize];
+    for y in 0..height {
+        for x in 0..width {
+            if !*image.get_pixel(x, y) {
+                continue;
+            }
+            let (left, up) = (x.wrapping_sub(1), y.wrapping_sub(1));
+            let mut neighbors = [(left, y), (left, up), (x, up), (x + 1, up)]
+                .into_iter()
+                .filter(|&(x, y)| x < width && y < height)
+                .map(|(x, y)| labels[index(x, y)])
+                .filter(|&label| label != 0);
+            labels[index(x, y)] = if let Some(label) = neighbors.next() {
+                for other in neighbors {
+                    union(&mut parents, label, other);
+                }
+                label
+            } else {
+                parents.push(parents.len());
+                parents.len() - 1
+            };
+        }
+    }
+    (labels, parents)
+}
+
+/// Labels pixels by flooding every object from its first unlabeled pixel.
+///
+/// Returns the label of every pixel, `0` for transparent pixels, and the parent of every label.
+fn flood_fill<I>(image: &I) -> (Vec<usize>, Vec<usize>)
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    let index = |x: u32, y: u32| y as usize * width as usize + x as usize;
+
+    let mut labels = vec![0usize; width as usize * height as usize];
+    let mut parents = vec![0usize];
+    let mut stack = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if labels[index(x, y)] != 0 || !*image.get_pixel(x, y) {
+                continue;
+            }
+            let label = parents.len();
+            parents.push(label);
+            labels[index(x, y)] = label;
+            stack.push((x, y));
+            while let Some((x, y)) = stack.pop() {
+                for (dx, dy) in [
+                    (-1, -1),
+                    (0, -1),
+                    (1, -1),
+                    (-1, 0),
+                    (1, 0),
+                    (-1, 1),
+                    (0, 1),
+                    (1, 1),
+                ] {
+                    let (x, y) = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+                    if x < width && y < height && labels[index(x, y)] == 0 && *image.get_pixel(x, y)
+                    {
+                        labels[index(x, y)] = label;
+                        stack.push((x, y));
+                    }
+                }
+            }
+        }
+    }
+    (labels, parents)
+}
+
+/// Merges polygons whose bounding boxes are at most `distance` apart by concatenating their points.
+///
+/// Merging is transitive, and every merged polygon takes the position of its first polygon.
+pub fn merge_nearby(polygons: Vec<Vec<UVec2>>, distance: f32) -> Vec<Vec<UVec2>> {
+    let bounds: Vec<(Vec2, Vec2)> = polygons
+        .iter()
+        .map(|polygon| {
+            let points = polygon.iter().map(UVec2::as_vec2);
+            (
+                points.clone().fold(Vec2::MAX, Vec2::min),
+                points.fold(Vec2::MIN, Vec2::max),
+            )
+        })
+        .collect();
+
+    let mut parents: Vec<usize> = (0..polygons.len()).collect();
+    for (a, (a_min, a_max)) in bounds.iter().enumerate() {
+        for (b, (b_min, b_max)) in bounds.iter().enumerate().skip(a + 1) {
+            let gap = (*a_min - *b_max).max(*b_min - *a_max).max(Vec2::ZERO);
+            if gap.length() <= distance {
+                union(&mut parents, a, b);
+            }
+        }
+    }
+
+    let mut merged: Vec<Vec<UVec2>> = Vec::new();
+    let mut ids = vec![usize::MAX; polygons.len()];
+    for (index, polygon) in polygons.into_iter().enumerate() {
+        let root = find(&mut parents, index);
+        if ids[root] == usize::MAX {
+            ids[root] = merged.len();
+            merged.push(polygon);
+        } else {
+            merged[ids[root]].extend(polygon);
+        }
+    }
+    merged
+}
+
+fn find(parents: &mut [usize], mut label: usize) -> usize {
+    while parents[label] != label {
+        parents[label] = parents[parents[label]];
+        label = parents[label];
+    }
+    label
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parents, a), find(parents, b));
+    parents[a.max(b)] = a.min(b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALGORITHMS: [ConnectedComponentsAlgo; 2] = [
+        ConnectedComponentsAlgo::FloodFill,
+        ConnectedComponentsAlgo::TwoPassUnionFind,
+    ];
+
+    /// Creates an image from rows of `#` for opaque and `.` for transparent pixels.
+    fn image(rows: &[&str]) -> BinaryImage {
+        let width = u32::try_from(rows[0].len()).unwrap();
+        let height = u32::try_from(rows.len()).unwrap();
+        let pixels: Vec<u8> = rows
+            .iter()
+            .flat_map(|row| row.bytes())
+            .map(|cell| u8::from(cell == b'#'))
+            .collect();
+        from_pixels(width, height, &pixels)
+    }
+
+    #[test]
+    fn diagonal_pixels_are_connected() {
+        // Diagonal neighbors are separate objects with 4-connectivity, but one with 8-connectivity.
+        let diagonal = image(&["#.", ".#"]);
+        let anti_diagonal = image(&[".#", "#."]);
+        let apart = image(&["#.#"]);
+        for algorithm in ALGORITHMS {
+            assert_eq!(count(&diagonal, algorithm), 1, "{algorithm:?}");
+            assert_eq!(count(&anti_diagonal, algorithm), 1, "{algorithm:?}");
+            assert_eq!(count(&apart, algorithm), 2, "{algorithm:?}");
+            assert_eq!(components(&diagonal, algorithm).len(), 1, "{algorithm:?}");
+        }
+    }
+
+    #[test]
+    fn arms_merging_in_a_later_row() {
+        // The arms get separate labels in the first rows, which are merged by the bottom row.
+        let image = image(&["#...#", "#...#", "#...#", "#####", "....."]);
+        for algorithm in ALGORITHMS {
+            assert_eq!(count(&image, algorithm), 1, "{algorithm:?}");
+            let components = components(&image, algorithm);
+            assert_eq!(components.len(), 1, "{algorithm:?}");
+            let (offset, component) = &components[0];
+            assert_eq!(*offset, UVec2::ZERO);
+            assert_eq!(component.dimensions(), (5, 4));
+            assert_eq!(
+                component.pixels().filter(|(_, _, pixel)| **pixel).count(),
+                11
+            );
+        }
+    }
+
+    #[test]
+    fn merge_blobs_within_distance() {
+        let square = |x: u32| {
+            vec![
+                UVec2::new(x, 0),
+                UVec2::new(x + 2, 0),
+                UVec2::new(x + 2, 2),
+                UVec2::new(x, 2),
+            ]
+        };
+        // The bounding boxes of the squares are 3 pixels apart.
+        let polygons = vec![square(0), square(5)];
+        let merged = merge_nearby(polygons.clone(), 3.);
+        assert_eq!(merged, vec![[square(0), square(5)].concat()]);
+        assert_eq!(merge_nearby(polygons.clone(), 2.9), polygons);
+    }
+
+    #[test]
+    fn merging_is_transitive() {
+        let point = |x: u32| vec![UVec2::new(x, 0)];
+        let merged = merge_nearby(vec![point(0), point(10), point(5)], 5.);
+        assert_eq!(
+            merged,
+            vec![vec![UVec2::new(0, 0), UVec2::new(10, 0), UVec2::new(5, 0)]]
+        );
+    }
+}
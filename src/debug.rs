@@ -0,0 +1,277 @@
+#[cfg(feature = "avian2d")]
+use avian2d::parry;
+use bevy::{color::palettes::css, prelude::*};
+#[cfg(all(feature = "rapier2d", not(feature = "avian2d")))]
+use bevy_rapier2d::rapier::parry;
+#[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+use parry::{
+    math::{Isometry, Point},
+    shape::{Shape, TypedShape},
+};
+
+use crate::abstract_collider::AbstractCollider::{
+    self, ConvexHull, ConvexPolyline, Heightfield, Polyline, Trimesh,
+};
+
+/// A plugin drawing the outlines of all [`DebugCollider`] entities with gizmos.
+///
+/// With the `rapier2d` or `avian2d` feature, the plugin also draws the `Collider` components of the enabled
+/// backends, so colliders generated by the builder are drawn without adding a [`DebugCollider`].
+/// Entities with a [`DebugCollider`] only draw that.
+///
+/// This is a lightweight alternative to the debug render plugins of rapier2d and avian2d
+/// that works the same for both backends, or without one. The app has to include Bevy's `GizmoPlugin`,
+/// which is part of the `DefaultPlugins`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColliderDebugPlugin {
+    /// The color of the outlines.
+    pub color: Color,
+}
+
+impl Default for ColliderDebugPlugin {
+    fn default() -> Self {
+        Self {
+            color: css::LIME.into(),
+        }
+    }
+}
+
+impl Plugin for ColliderDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DebugColor(self.color))
+            .add_systems(PostUpdate, draw_colliders);
+        #[cfg(feature = "rapier2d")]
+        app.add_systems(PostUpdate, draw_rapier_colliders);
+        #[cfg(feature = "avian2d")]
+        app.add_systems(PostUpdate, draw_avian_colliders);
+    }
+}
+
+/// A component holding the generated collider of an entity, drawn by the [`ColliderDebugPlugin`].
+///
+/// The outline is drawn relative to the entity's `GlobalTransform`.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct DebugCollider(pub AbstractCollider);
+
+#[derive(Resource)]
+struct DebugColor(Color);
+
+#[allow(clippy::needless_pass_by_value)]
+fn draw_colliders(
+    mut gizmos: Gizmos,
+    color: Res<DebugColor>,
+    colliders: Query<(&DebugCollider, &GlobalTransform)>,
+) {
+    for (DebugCollider(collider), transform) in &colliders {
+        let point = |point: Vec2| transform.transform_point(point.extend(0.));
+        match collider {
+            Polyline(points) | ConvexPolyline(points) | ConvexHull(points) => {
+                gizmos.linestrip(
+                    points.iter().chain(points.first()).copied().map(point),
+                    color.0,
+                );
+            }
+            Trimesh(points, indices) => {
+                for triangle in indices {
+                    gizmos.linestrip(
+                        [triangle[0], triangle[1], triangle[2], triangle[0]]
+                            .into_iter()
+                            .filter_map(|index| points.get(index as usize))
+                            .copied()
+                            .map(point),
+                        color.0,
+                    );
+                }
+            }
+            Heightfield(heights, scale) => {
+                let step = scale.x / heights.len().saturating_sub(1).max(1) as f32;
+                gizmos.linestrip(
+                    heights.iter().enumerate().map(|(column, height)| {
+                        point(Vec2::new(
+                            column as f32 * step - scale.x / 2.,
+                            height * scale.y,
+                        ))
+                    }),
+                    color.0,
+                );
+            }
+        }
+    }
+}
+
+/// The number of segments of the outline of a ball or capsule's half circle.
+#[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+const CIRCLE_SUBDIVISIONS: u32 = 16;
+
+#[cfg(feature = "rapier2d")]
+#[allow(clippy::needless_pass_by_value)]
+fn draw_rapier_colliders(
+    mut gizmos: Gizmos,
+    color: Res<DebugColor>,
+    colliders: Query<(&bevy_rapier2d::prelude::Collider, &GlobalTransform), Without<DebugCollider>>,
+) {
+    for (collider, transform) in &colliders {
+        draw_shape(&mut gizmos, &*collider.raw, transform, color.0);
+    }
+}
+
+#[cfg(feature = "avian2d")]
+#[allow(clippy::needless_pass_by_value)]
+fn draw_avian_colliders(
+    mut gizmos: Gizmos,
+    color: Res<DebugColor>,
+    colliders: Query<(&avian2d::prelude::Collider, &GlobalTransform), Without<DebugCollider>>,
+) {
+    for (collider, transform) in &colliders {
+        draw_shape(&mut gizmos, &**collider.shape_scaled(), transform, color.0);
+    }
+}
+
+/// Draws the outline of a backend's shape.
+///
+/// The backends scale the shape by the entity's scale, so only its rotation and translation are applied.
+#[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+fn draw_shape(gizmos: &mut Gizmos, shape: &dyn Shape, transform: &GlobalTransform, color: Color) {
+    let (_, rotation, translation) = transform.to_scale_rotation_translation();
+    let transform = Transform::from_translation(translation).with_rotation(rotation);
+    let mut outlines = Vec::new();
+    shape_outlines(shape, &Isometry::identity(), &mut outlines);
+    for outline in outlines {
+        gizmos.linestrip(
+            outline
+                .into_iter()
+                .map(|point| transform.transform_point(Vec3::new(point.x, point.y, 0.))),
+            color,
+        );
+    }
+}
+
+/// Collects the outlines of a shape and its parts, closing every loop.
+#[cfg(any(feature = "rapier2d", feature = "avian2d"))]
+fn shape_outlines(
+    shape: &dyn Shape,
+    isometry: &Isometry<f32>,
+    outlines: &mut Vec<Vec<Point<f32>>>,
+) {
+    let mut push = |points: Vec<Point<f32>>, closed: bool| {
+        let mut outline: Vec<Point<f32>> = points.iter().map(|point| isometry * point).collect();
+        if closed {
+            outline.extend(outline.first().copied());
+        }
+        outlines.push(outline);
+    };
+    match shape.as_typed_shape() {
+        TypedShape::Ball(ball) => push(ball.to_polyline(CIRCLE_SUBDIVISIONS * 2), true),
+        TypedShape::Cuboid(cuboid) => push(cuboid.to_polyline(), true),
+        TypedShape::Capsule(capsule) => push(capsule.to_polyline(CIRCLE_SUBDIVISIONS), true),
+        TypedShape::Segment(segment) => push(vec![segment.a, segment.b], false),
+        TypedShape::Triangle(triangle) => push(triangle.vertices().to_vec(), true),
+        TypedShape::ConvexPolygon(polygon) => push(polygon.points().to_vec(), true),
+        TypedShape::Polyline(polyline) => {
+            for segment in polyline.segments() {
+                push(vec![segment.a, segment.b], false);
+            }
+        }
+        TypedShape::TriMesh(trimesh) => {
+            for triangle in trimesh.triangles() {
+                push(triangle.vertices().to_vec(), true);
+            }
+        }
+        TypedShape::HeightField(heightfield) => {
+            for segment in heightfield.segments() {
+                push(vec![segment.a, segment.b], false);
+            }
+        }
+        TypedShape::Compound(compound) => {
+            for (part_isometry, part) in compound.shapes() {
+                shape_outlines(&**part, &(isometry * part_isometry), outlines);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{asset::AssetPlugin, gizmos::LineGizmo, render::render_resource::Shader};
+
+    use super::*;
+
+    /// Creates a headless app with the plugin, without rendering.
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Shader>()
+            .add_plugins((bevy::gizmos::GizmoPlugin, ColliderDebugPlugin::default()));
+        app
+    }
+
+    /// Runs the app once and returns the drawn line strips.
+    fn line_strips(app: &mut App) -> Vec<Vec<Vec3>> {
+        app.update();
+        app.world()
+            .resource::<Assets<LineGizmo>>()
+            .iter()
+            .filter(|(_, gizmo)| gizmo.strip)
+            .flat_map(|(_, gizmo)| gizmo.positions.split(|position| position.is_nan()))
+            .filter(|strip| !strip.is_empty())
+            .map(<[Vec3]>::to_vec)
+            .collect()
+    }
+
+    fn square() -> AbstractCollider {
+        ConvexPolyline(vec![
+            Vec2::new(-1., -1.),
+            Vec2::new(1., -1.),
+            Vec2::new(1., 1.),
+            Vec2::new(-1., 1.),
+        ])
+    }
+
+    #[test]
+    fn draws_debug_colliders() {
+        let mut app = app();
+        app.world_mut().spawn((
+            DebugCollider(square()),
+            GlobalTransform::from_translation(Vec3::new(10., 0., 0.)),
+        ));
+        let strips = line_strips(&mut app);
+        assert_eq!(strips.len(), 1);
+        assert_eq!(strips[0].len(), 5);
+        assert_eq!(strips[0][0], Vec3::new(9., -1., 0.));
+        assert_eq!(strips[0][0], strips[0][4]);
+    }
+
+    #[cfg(feature = "rapier2d")]
+    #[test]
+    fn draws_rapier_colliders() {
+        let mut app = app();
+        let collider = square().to_rapier().unwrap();
+        app.world_mut().spawn((
+            collider.clone(),
+            GlobalTransform::from_translation(Vec3::new(10., 0., 0.)),
+        ));
+        app.world_mut()
+            .spawn((collider, DebugCollider(square()), GlobalTransform::IDENTITY));
+        let strips = line_strips(&mut app);
+        assert_eq!(strips.len(), 2);
+        assert!(strips.iter().all(|strip| strip.len() == 5));
+        assert!(strips
+            .iter()
+            .any(|strip| strip.contains(&Vec3::new(9., -1., 0.))));
+    }
+
+    #[cfg(feature = "avian2d")]
+    #[test]
+    fn draws_avian_colliders() {
+        let mut app = app();
+        app.world_mut().spawn((
+            square().to_avian().unwrap(),
+            GlobalTransform::from_translation(Vec3::new(10., 0., 0.)),
+        ));
+        let strips = line_strips(&mut app);
+        assert_eq!(strips.len(), 1);
+        assert_eq!(strips[0].len(), 5);
+        assert!(strips[0].contains(&Vec3::new(9., -1., 0.)));
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+
+use bevy::{image::IntoDynamicImageError, prelude::*};
+use edges::binary_image::BinaryImage;
+use image::{DynamicImage, ImageBuffer, Rgba};
+
+use crate::{
+    abstract_collider::AbstractCollidersBuilder, components::ConnectedComponentsAlgo,
+    utils::from_pixels,
+};
+
+/// A builder for the edge extraction step, converting color images into the binary images
+/// colliders are generated from.
+///
+/// It holds every setting of the edge detection in one value, which can be stored, serialized
+/// and reused for many images. By default, every pixel with a non-zero alpha value is opaque,
+/// all objects are kept and [`ConnectedComponentsAlgo::TwoPassUnionFind`] is used, which matches the
+/// `From` and `TryFrom` implementations of [`AbstractCollidersBuilder`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EdgesBuilder {
+    channel: ColorChannel,
+    threshold: u8,
+    inverted: bool,
+    min_area: u32,
+    algorithm: ConnectedComponentsAlgo,
+}
+
+/// The color channel an [`EdgesBuilder`] reads to decide whether a pixel is opaque.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorChannel {
+    Red,
+    Green,
+    Blue,
+    #[default]
+    Alpha,
+    /// The perceived brightness of the red, green and blue channels, using the Rec. 709 weights.
+    Luminance,
+}
+
+impl ColorChannel {
+    /// Returns the value of the channel for an RGBA8 pixel.
+    fn value(self, pixel: &[u8]) -> u8 {
+        match self {
+            Self::Red => pixel[0],
+            Self::Green => pixel[1],
+            Self::Blue => pixel[2],
+            Self::Alpha => pixel[3],
+            Self::Luminance => {
+                let luminance = 2126 * u32::from(pixel[0])
+                    + 7152 * u32::from(pixel[1])
+                    + 722 * u32::from(pixel[2]);
+                u8::try_from(luminance / 10000).unwrap_or(u8::MAX)
+            }
+        }
+    }
+}
+
+impl EdgesBuilder {
+    /// Creates a new `EdgesBuilder` with the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the color channel used to decide whether a pixel is opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel` - The color channel, [`ColorChannel::Alpha`] by default.
+    ///
+    /// # Returns
+    ///
+    /// A new `EdgesBuilder` instance with the updated channel.
+    #[must_use]
+    pub fn color_channel(self, channel: ColorChannel) -> Self {
+        Self { channel, ..self }
+    }
+
+    /// Sets the channel value a pixel has to exceed to be opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The channel threshold, `0` treats every pixel with a non-zero value as opaque.
+    ///
+    /// # Returns
+    ///
+    /// A new `EdgesBuilder` instance with the updated threshold.
+    #[must_use]
+    pub fn threshold(self, threshold: u8) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Sets whether transparent pixels should generate colliders instead of opaque ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `inverted` - Whether the opacity of pixels is inverted.
+    ///
+    /// # Returns
+    ///
+    /// A new `EdgesBuilder` instance with the updated setting.
+    #[must_use]
+    pub fn inverted(self, inverted: bool) -> Self {
+        Self { inverted, ..self }
+    }
+
+    /// Sets the minimum area of objects, smaller objects don't generate colliders.
+    ///
+    /// See [`AbstractCollidersBuilder::min_area`].
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The minimum area in square pixels, `0` keeps all objects.
+    ///
+    /// # Returns
+    ///
+    /// A new `EdgesBuilder` instance with the updated minimum area.
+    #[must_use]
+    pub fn min_area(self, area: u32) -> Self {
+        Self {
+            min_area: area,
+            ..self
+        }
+    }
+
+    /// Sets the algorithm used to separate the opaque pixels into objects.
+    ///
+    /// See [`AbstractCollidersBuilder::with_connected_components_algorithm`].
+    ///
+    /// # Arguments
+    ///
+    /// * `algorithm` - The algorithm, [`ConnectedComponentsAlgo::TwoPassUnionFind`] by default.
+    ///
+    /// # Returns
+    ///
+    /// A new `EdgesBuilder` instance with the updated algorithm.
+    #[must_use]
+    pub fn connected_components_algorithm(self, algorithm: ConnectedComponentsAlgo) -> Self {
+        Self { algorithm, ..self }
+    }
+
+    /// Creates an [`AbstractCollidersBuilder`] from a Bevy image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image can't be converted into a [`DynamicImage`].
+    pub fn image(
+        self,
+        image: &Image,
+    ) -> Result<AbstractCollidersBuilder<BinaryImage>, IntoDynamicImageError> {
+        image
+            .clone()
+            .try_into_dynamic()
+            .map(|image| self.dynamic_image(&image))
+    }
+
+    /// Creates an [`AbstractCollidersBuilder`] from a dynamic image.
+    #[must_use]
+    pub fn dynamic_image(self, image: &DynamicImage) -> AbstractCollidersBuilder<BinaryImage> {
+        self.binarize(&image.to_rgba8())
+    }
+
+    /// Creates one [`AbstractCollidersBuilder`] per physics layer of a color coded image.
+    ///
+    /// Every opaque pixel belongs to the layer whose color is nearest to the pixel's color,
+    /// so art pipelines can mark surfaces of different collision layers with different colors.
+    /// Layers listed with several colors are combined.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The color coded image.
+    /// * `layers` - The colors of the layers, each with the layer's bitmask.
+    ///
+    /// # Returns
+    ///
+    /// A map from each layer's bitmask to the builder of its pixels, layers without pixels are omitted.
+    #[must_use]
+    pub fn layers(
+        self,
+        image: &DynamicImage,
+        layers: &[(Color, u32)],
+    ) -> HashMap<u32, AbstractCollidersBuilder<BinaryImage>> {
+        let image = image.to_rgba8();
+        let opaque = self.classify(&image);
+        let colors: Vec<[u8; 4]> = layers
+            .iter()
+            .map(|(color, _)| color.to_srgba().to_u8_array())
+            .collect();
+        let pixel_layers: Vec<Option<u32>> = image
+            .pixels()
+            .zip(opaque)
+            .map(|(pixel, opaque)| {
+                let distance = |color: &[u8; 4]| {
+                    (0..3)
+                        .map(|channel| u32::from(pixel[channel].abs_diff(color[channel])).pow(2))
+                        .sum::<u32>()
+                };
+                let nearest = (0..layers.len()).min_by_key(|&index| distance(&colors[index]));
+                nearest.filter(|_| opaque == 1).map(|index| layers[index].1)
+            })
+            .collect();
+
+        let mut builders = HashMap::new();
+        for &(_, layer) in layers {
+            if builders.contains_key(&layer) || !pixel_layers.contains(&Some(layer)) {
+                continue;
+            }
+            let pixels: Vec<u8> = pixel_layers
+                .iter()
+                .map(|pixel_layer| u8::from(*pixel_layer == Some(layer)))
+                .collect();
+            let builder =
+                AbstractCollidersBuilder::new(from_pixels(image.width(), image.height(), &pixels))
+                    .min_area(self.min_area)
+                    .with_connected_components_algorithm(self.algorithm);
+            builders.insert(layer, builder);
+        }
+        builders
+    }
+
+    /// Creates an [`AbstractCollidersBuilder`] from raw RGBA8 pixel data.
+    ///
+    /// # Returns
+    ///
+    /// An `AbstractCollidersBuilder` instance, or `None` if `data` is smaller than `width * height * 4` bytes.
+    #[must_use]
+    pub fn rgba8(
+        self,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) -> Option<AbstractCollidersBuilder<BinaryImage>> {
+        let size = width as usize * height as usize * 4;
+        let data = data.get(..size)?;
+        ImageBuffer::<Rgba<u8>, _>::from_raw(width, height, data).map(|image| self.binarize(&image))
+    }
+
+    fn binarize<C>(self, image: &ImageBuffer<Rgba<u8>, C>) -> AbstractCollidersBuilder<BinaryImage>
+    where
+        C: std::ops::Deref<Target = [u8]>,
+    {
+        let pixels = self.classify(image);
+        AbstractCollidersBuilder::new(from_pixels(image.width(), image.height(), &pixels))
+            .min_area(self.min_area)
+            .with_connected_components_algorithm(self.algorithm)
+    }
+
+    /// Returns one value per pixel of RGBA8 pixel data, `1` for opaque and `0` for transparent pixels.
+    #[cfg(not(feature = "simd"))]
+    fn classify(self, data: &[u8]) -> Vec<u8> {
+        self.classify_scalar(data)
+    }
+
+    /// Returns one value per pixel of RGBA8 pixel data, `1` for opaque and `0` for transparent pixels.
+    ///
+    /// Eight pixels are classified at once, the remaining pixels are classified one by one.
+    /// [`ColorChannel::Luminance`] is always classified one pixel at a time.
+    #[cfg(feature = "simd")]
+    fn classify(self, data: &[u8]) -> Vec<u8> {
+        use wide::u32x8;
+
+        let shift: u32 = match self.channel {
+            ColorChannel::Red => 0,
+            ColorChannel::Green => 8,
+            ColorChannel::Blue => 16,
+            ColorChannel::Alpha => 24,
+            ColorChannel::Luminance => return self.classify_scalar(data),
+        };
+        let threshold = u32x8::splat(u32::from(self.threshold));
+        let inverted = u8::from(self.inverted);
+
+        let mut pixels = Vec::with_capacity(data.len() / 4);
+        let mut chunks = data.chunks_exact(32);
+        for chunk in &mut chunks {
+            let lanes: [u32; 8] = std::array::from_fn(|lane| {
+                u32::from_le_bytes([
+                    chunk[lane * 4],
+                    chunk[lane * 4 + 1],
+                    chunk[lane * 4 + 2],
+                    chunk[lane * 4 + 3],
+                ])
+            });
+            let opaque = ((u32x8::new(lanes) >> shift) & u32x8::splat(0xFF)).cmp_gt(threshold);
+            pixels.extend(opaque.to_array().map(|lane| u8::from(lane != 0) ^ inverted));
+        }
+        pixels.extend(self.classify_scalar(chunks.remainder()));
+        pixels
+    }
+
+    fn classify_scalar(self, data: &[u8]) -> Vec<u8> {
+        data.chunks_exact(4)
+            .map(|pixel| u8::from((self.channel.value(pixel) > self.threshold) != self.inverted))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::palettes::css;
+    use edges::binary_image::Bit;
+    use image::GenericImageView;
+
+    use super::*;
+
+    #[test]
+    fn one_builder_per_layer() {
+        // Red on the left, blue on the right and a transparent bottom row.
+        let image = ImageBuffer::from_fn(4, 3, |x, y| match (x, y) {
+            (_, 2) => Rgba([0u8, 0, 0, 0]),
+            (0 | 1, _) => Rgba([250, 10, 0, 255]),
+            _ => Rgba([0, 0, 240, 255]),
+        });
+        let layers = [
+            (Color::from(css::RED), 1),
+            (Color::from(css::BLUE), 2),
+            (Color::from(css::LIME), 4),
+        ];
+        let builders = EdgesBuilder::new().layers(&DynamicImage::from(image), &layers);
+        assert_eq!(builders.len(), 2);
+        for (layer, columns) in [(1, 0..2), (2, 2..4)] {
+            let builder = &builders[&layer];
+            for (x, y, pixel) in builder.image().pixels() {
+                assert_eq!(
+                    pixel,
+                    Bit::from(y < 2 && columns.contains(&x)),
+                    "layer {layer}, ({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_matches_scalar() {
+        // 29 pixels, so three full chunks of eight pixels and a remainder of five.
+        let data: Vec<u8> = (0..29u8 * 4).map(|byte| byte.wrapping_mul(37)).collect();
+        for channel in [
+            ColorChannel::Red,
+            ColorChannel::Green,
+            ColorChannel::Blue,
+            ColorChannel::Alpha,
+            ColorChannel::Luminance,
+        ] {
+            for inverted in [false, true] {
+                let builder = EdgesBuilder::new()
+                    .color_channel(channel)
+                    .threshold(127)
+                    .inverted(inverted);
+                assert_eq!(
+                    builder.classify(&data),
+                    builder.classify_scalar(&data),
+                    "{channel:?}, inverted: {inverted}"
+                );
+            }
+        }
+    }
+}
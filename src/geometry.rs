@@ -0,0 +1,736 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use bevy::prelude::*;
+
+/// Returns an iterator over the segments of a closed polygon, including the one from the last point to the first.
+pub fn segments(polygon: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+/// Checks whether a point lies inside a closed polygon using the even-odd ray casting rule.
+pub fn contains_point(polygon: &[Vec2], point: Vec2) -> bool {
+    segments(polygon).fold(false, |inside, (a, b)| {
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            !inside
+        } else {
+            inside
+        }
+    })
+}
+
+/// Returns the point on the edge of a closed polygon that is closest to `point`.
+pub fn closest_point(polygon: &[Vec2], point: Vec2) -> Option<Vec2> {
+    segments(polygon)
+        .map(|segment| closest_on_segment(point, segment))
+        .min_by(|a, b| {
+            a.distance_squared(point)
+                .total_cmp(&b.distance_squared(point))
+        })
+}
+
+/// Returns the point on a segment that is closest to `point`.
+fn closest_on_segment(point: Vec2, (a, b): (Vec2, Vec2)) -> Vec2 {
+    let t = (point - a).dot(b - a) / (b - a).length_squared();
+    if t.is_finite() {
+        a.lerp(b, t.clamp(0., 1.))
+    } else {
+        a
+    }
+}
+
+/// Returns the topmost segment of a closed polygon at the horizontal position `x`.
+///
+/// Vertical segments are skipped, since their ends are shared with the neighboring segments.
+pub fn topmost_segment(polygon: &[Vec2], x: f32) -> Option<(Vec2, Vec2)> {
+    segments(polygon)
+        .filter(|(a, b)| (a.x - b.x).abs() > f32::EPSILON && a.x.min(b.x) <= x && x <= a.x.max(b.x))
+        .map(|(a, b)| if a.x < b.x { (a, b) } else { (b, a) })
+        .max_by(|a, b| y_at(*a, x).total_cmp(&y_at(*b, x)))
+}
+
+/// Returns the y-coordinate of a non-vertical segment at the horizontal position `x`.
+pub fn y_at((a, b): (Vec2, Vec2), x: f32) -> f32 {
+    a.y.lerp(b.y, (x - a.x) / (b.x - a.x))
+}
+
+/// Returns the axis-aligned bounding box of a polygon as `(min, max)`.
+pub fn bounding_box(polygon: &[Vec2]) -> Option<(Vec2, Vec2)> {
+    polygon
+        .iter()
+        .map(|p| (*p, *p))
+        .reduce(|(min, max), (a, b)| (min.min(a), max.max(b)))
+}
+
+/// Returns the orientation of `c` relative to the line through `a` and `b`.
+///
+/// Positive if `c` lies to the left of the line, negative if to the right, zero if collinear.
+fn orientation(a: Vec2, b: Vec2, c: Vec2) -> f32 {
+    (b - a).perp_dot(c - a)
+}
+
+/// Checks whether a point collinear to a segment lies within the segment's bounds.
+fn on_segment(point: Vec2, (a, b): (Vec2, Vec2)) -> bool {
+    point.cmpge(a.min(b)).all() && point.cmple(a.max(b)).all()
+}
+
+/// Checks whether two segments intersect, including touching and collinear overlapping segments.
+pub fn segments_intersect((a, b): (Vec2, Vec2), (c, d): (Vec2, Vec2)) -> bool {
+    let (d1, d2) = (orientation(c, d, a), orientation(c, d, b));
+    let (d3, d4) = (orientation(a, b, c), orientation(a, b, d));
+    if d1 * d2 < 0. && d3 * d4 < 0. {
+        return true;
+    }
+    (d1 == 0. && on_segment(a, (c, d)))
+        || (d2 == 0. && on_segment(b, (c, d)))
+        || (d3 == 0. && on_segment(c, (a, b)))
+        || (d4 == 0. && on_segment(d, (a, b)))
+}
+
+/// Checks whether two closed polygons overlap.
+///
+/// The bounding boxes are compared first as a fast reject, then the polygons are tested
+/// for intersecting segments and for one polygon being contained in the other.
+pub fn intersects(a: &[Vec2], b: &[Vec2]) -> bool {
+    let (Some((a_min, a_max)), Some((b_min, b_max))) = (bounding_box(a), bounding_box(b)) else {
+        return false;
+    };
+    if a_max.cmplt(b_min).any() || b_max.cmplt(a_min).any() {
+        return false;
+    }
+    segments(a).any(|sa| segments(b).any(|sb| segments_intersect(sa, sb)))
+        || contains_point(b, a[0])
+        || contains_point(a, b[0])
+}
+
+/// Checks whether a closed polygon is simple, i.e. none of its segments intersect or touch
+/// apart from consecutive segments sharing their common point.
+///
+/// Duplicate consecutive points and consecutive segments folding back onto each other are rejected first,
+/// then the segments are swept from left to right, so only segments overlapping horizontally are tested.
+pub fn is_simple(polygon: &[Vec2]) -> bool {
+    let n = polygon.len();
+    let folds = (0..n).any(|i| {
+        let (a, b, c) = (polygon[i], polygon[(i + 1) % n], polygon[(i + 2) % n]);
+        a == b || (orientation(a, b, c) == 0. && (a - b).dot(c - b) > 0.)
+    });
+    if folds {
+        return false;
+    }
+    let mut sorted: Vec<(usize, (Vec2, Vec2))> = segments(polygon).enumerate().collect();
+    sorted.sort_by(|(_, a), (_, b)| a.0.x.min(a.1.x).total_cmp(&b.0.x.min(b.1.x)));
+
+    let mut active: Vec<(usize, (Vec2, Vec2))> = Vec::new();
+    for (i, segment) in sorted {
+        let left = segment.0.x.min(segment.1.x);
+        active.retain(|(_, (a, b))| a.x.max(b.x) >= left);
+        let is_neighbor = |j: usize| (i + 1) % n == j || (j + 1) % n == i;
+        if active
+            .iter()
+            .any(|&(j, other)| !is_neighbor(j) && segments_intersect(segment, other))
+        {
+            return false;
+        }
+        active.push((i, segment));
+    }
+    true
+}
+
+/// Returns the signed area of a closed polygon, positive for counter-clockwise winding.
+pub fn signed_area(polygon: &[Vec2]) -> f32 {
+    segments(polygon).map(|(a, b)| a.perp_dot(b)).sum::<f32>() / 2.
+}
+
+/// Returns the area, centroid and moment of inertia around the centroid of a closed polygon with unit density.
+///
+/// Returns `None` if the polygon has no area.
+#[cfg(feature = "rapier2d")]
+pub fn mass_properties(polygon: &[Vec2]) -> Option<(f32, Vec2, f32)> {
+    let (area, moment, inertia) =
+        segments(polygon).fold((0., Vec2::ZERO, 0.), |(area, moment, inertia), (a, b)| {
+            let cross = a.perp_dot(b);
+            (
+                area + cross / 2.,
+                moment + (a + b) * cross / 6.,
+                inertia + cross * (a.dot(a) + a.dot(b) + b.dot(b)) / 12.,
+            )
+        });
+    (area != 0.).then(|| {
+        let centroid = moment / area;
+        (
+            area.abs(),
+            centroid,
+            (inertia - area * centroid.length_squared()).abs(),
+        )
+    })
+}
+
+/// Returns the perimeter of a closed polygon.
+pub fn perimeter(polygon: &[Vec2]) -> f32 {
+    segments(polygon).map(|(a, b)| a.distance(b)).sum()
+}
+
+/// Checks whether a point lies inside or on the border of a counter-clockwise triangle.
+fn in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    orientation(a, b, point) >= 0.
+        && orientation(b, c, point) >= 0.
+        && orientation(c, a, point) >= 0.
+}
+
+/// Triangulates a simple closed polygon using ear clipping.
+///
+/// Collinear vertices are skipped without producing degenerate triangles.
+///
+/// # Returns
+///
+/// The indices of the triangles' vertices in counter-clockwise order,
+/// or `None` if the polygon is degenerate and can't be triangulated.
+pub fn triangulate(polygon: &[Vec2]) -> Option<Vec<[u32; 3]>> {
+    let mut indices: Vec<u32> = (0..u32::try_from(polygon.len()).ok()?).collect();
+    if signed_area(polygon) < 0. {
+        indices.reverse();
+    }
+    let vertex = |index: u32| polygon[index as usize];
+
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    while indices.len() >= 3 {
+        let n = indices.len();
+        let corner = |i: usize| (indices[(i + n - 1) % n], indices[i], indices[(i + 1) % n]);
+        if let Some(i) = (0..n).find(|&i| {
+            let (a, b, c) = corner(i);
+            orientation(vertex(a), vertex(b), vertex(c)) == 0.
+        }) {
+            indices.remove(i);
+            continue;
+        }
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = corner(i);
+            let (a, b, c) = (vertex(a), vertex(b), vertex(c));
+            orientation(a, b, c) > 0.
+                && !indices.iter().map(|&j| vertex(j)).any(|point| {
+                    point != a && point != b && point != c && in_triangle(point, a, b, c)
+                })
+        })?;
+        let (a, b, c) = corner(ear);
+        triangles.push([a, b, c]);
+        indices.remove(ear);
+    }
+    (!triangles.is_empty()).then_some(triangles)
+}
+
+/// Smooths a closed polygon with `iterations` rounds of Chaikin's corner cutting.
+pub fn chaikin(mut polygon: Vec<Vec2>, iterations: u32) -> Vec<Vec2> {
+    for _ in 0..iterations {
+        if polygon.len() < 3 {
+            break;
+        }
+        polygon = segments(&polygon)
+            .flat_map(|(a, b)| [a.lerp(b, 0.25), a.lerp(b, 0.75)])
+            .collect();
+    }
+    polygon
+}
+
+/// Simplifies a closed polygon to at most `max_vertices` points with the Visvalingam-Whyatt algorithm.
+///
+/// The point forming the smallest triangle with its neighbors is removed until the budget is met,
+/// at least three points are kept.
+pub fn visvalingam(polygon: Vec<Vec2>, max_vertices: usize) -> Vec<Vec2> {
+    let max_vertices = max_vertices.max(3);
+    let n = polygon.len();
+    if n <= max_vertices {
+        return polygon;
+    }
+
+    let mut previous: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let area = |a: usize, b: usize, c: usize| orientation(polygon[a], polygon[b], polygon[c]).abs();
+    let mut areas: Vec<f32> = (0..n).map(|i| area(previous[i], i, next[i])).collect();
+    // Areas are never negative, so their bit patterns are ordered like the areas themselves.
+    let mut heap: BinaryHeap<Reverse<(u32, usize)>> = areas
+        .iter()
+        .enumerate()
+        .map(|(i, area)| Reverse((area.to_bits(), i)))
+        .collect();
+
+    let mut removed = vec![false; n];
+    let mut remaining = n;
+    while remaining > max_vertices {
+        let Some(Reverse((bits, i))) = heap.pop() else {
+            break;
+        };
+        if removed[i] || bits != areas[i].to_bits() {
+            continue;
+        }
+        removed[i] = true;
+        remaining -= 1;
+        let (a, b) = (previous[i], next[i]);
+        next[a] = b;
+        previous[b] = a;
+        for j in [a, b] {
+            areas[j] = area(previous[j], j, next[j]);
+            heap.push(Reverse((areas[j].to_bits(), j)));
+        }
+    }
+
+    polygon
+        .into_iter()
+        .zip(removed)
+        .filter_map(|(point, removed)| (!removed).then_some(point))
+        .collect()
+}
+
+/// Simplifies a closed polygon with the Ramer-Douglas-Peucker algorithm.
+///
+/// Points closer than `epsilon` to the segment between the kept points around them are removed.
+/// The loop is split at its first point and the point farthest from it, which are always kept.
+pub fn douglas_peucker(polygon: &[Vec2], epsilon: f32) -> Vec<Vec2> {
+    let n = polygon.len();
+    if n <= 3 {
+        return polygon.to_vec();
+    }
+    let first = polygon[0];
+    let farthest = (1..n)
+        .max_by(|&a, &b| {
+            polygon[a]
+                .distance_squared(first)
+                .total_cmp(&polygon[b].distance_squared(first))
+        })
+        .unwrap_or(n / 2);
+
+    let mut kept = vec![false; n];
+    kept[0] = true;
+    kept[farthest] = true;
+    let mut chains = vec![(0, farthest), (farthest, n)];
+    while let Some((start, end)) = chains.pop() {
+        let segment = (polygon[start], polygon[end % n]);
+        let Some((i, distance)) = (start + 1..end)
+            .map(|i| {
+                (
+                    i,
+                    polygon[i].distance(closest_on_segment(polygon[i], segment)),
+                )
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+        else {
+            continue;
+        };
+        if distance > epsilon {
+            kept[i] = true;
+            chains.push((start, i));
+            chains.push((i, end));
+        }
+    }
+
+    polygon
+        .iter()
+        .zip(kept)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+/// Simplifies a closed polygon to exactly `vertices` points.
+///
+/// The epsilon of [`douglas_peucker`] is found with a binary search between zero and the diagonal
+/// of the bounding box. At least three points are kept. If no epsilon results in exactly `vertices` points,
+/// the remaining points of the simplification with the fewest points above it are removed with [`visvalingam`].
+pub fn douglas_peucker_to(polygon: Vec<Vec2>, vertices: usize) -> Vec<Vec2> {
+    const ITERATIONS: u32 = 32;
+    let vertices = vertices.max(3);
+    if polygon.len() <= vertices {
+        return polygon;
+    }
+    let Some((min, max)) = bounding_box(&polygon) else {
+        return polygon;
+    };
+
+    let (mut low, mut high) = (0., min.distance(max));
+    let mut fewest: Option<Vec<Vec2>> = None;
+    for _ in 0..ITERATIONS {
+        let epsilon = f32::midpoint(low, high);
+        let simplified = douglas_peucker(&polygon, epsilon);
+        match simplified.len().cmp(&vertices) {
+            Ordering::Equal => return simplified,
+            Ordering::Greater => {
+                low = epsilon;
+                if fewest
+                    .as_ref()
+                    .is_none_or(|fewest| simplified.len() < fewest.len())
+                {
+                    fewest = Some(simplified);
+                }
+            }
+            Ordering::Less => high = epsilon,
+        }
+    }
+    visvalingam(fewest.unwrap_or(polygon), vertices)
+}
+
+/// Returns the convex hull of a set of points in counter-clockwise order using Andrew's monotone chain.
+pub fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    let mut hull: Vec<Vec2> = Vec::with_capacity(points.len() + 1);
+    let push = |hull: &mut Vec<Vec2>, point: Vec2, start: usize| {
+        while hull.len() >= start + 2
+            && orientation(hull[hull.len() - 2], hull[hull.len() - 1], point) <= 0.
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    };
+    for &point in &points {
+        push(&mut hull, point, 0);
+    }
+    let start = hull.len() - 1;
+    for &point in points.iter().rev().skip(1) {
+        push(&mut hull, point, start);
+    }
+    hull.pop();
+    hull
+}
+
+/// Merges consecutive points of a closed polygon that are at most `threshold` apart into the first of them.
+///
+/// The last point is also merged into the first point, so no zero-length segment remains when closing the polygon.
+pub fn weld(polygon: Vec<Vec2>, threshold: f32) -> Vec<Vec2> {
+    let mut welded: Vec<Vec2> = Vec::with_capacity(polygon.len());
+    for point in polygon {
+        if welded
+            .last()
+            .is_none_or(|last| last.distance(point) > threshold)
+        {
+            welded.push(point);
+        }
+    }
+    while welded.len() > 1 && welded[0].distance(welded[welded.len() - 1]) <= threshold {
+        welded.pop();
+    }
+    welded
+}
+
+/// Clips a closed polygon to the half-plane on the left of the line through `origin` in the direction of `axis`.
+///
+/// Segments crossing the line are cut at the intersection, points on the line are kept.
+pub fn clip_left(polygon: &[Vec2], axis: Vec2, origin: Vec2) -> Vec<Vec2> {
+    let side = |point: Vec2| axis.perp_dot(point - origin);
+    let mut clipped = Vec::with_capacity(polygon.len() + 2);
+    for (a, b) in segments(polygon) {
+        let (side_a, side_b) = (side(a), side(b));
+        if side_a >= 0. {
+            clipped.push(a);
+        }
+        if side_a * side_b < 0. {
+            clipped.push(a.lerp(b, side_a / (side_a - side_b)));
+        }
+    }
+    clipped
+}
+
+/// Inserts `factor - 1` evenly spaced points into every segment of a closed polygon.
+pub fn subdivide(polygon: &[Vec2], factor: u32) -> Vec<Vec2> {
+    let factor = factor.max(1);
+    segments(polygon)
+        .flat_map(|(a, b)| (0..factor).map(move |step| a.lerp(b, step as f32 / factor as f32)))
+        .collect()
+}
+
+/// Removes the points of a closed polygon that are collinear with their neighbors within `tolerance`.
+///
+/// A point is removed if the cross product of the segments to and from it is at most `tolerance`,
+/// after which its neighbors are checked again. At least two points are kept.
+pub fn remove_collinear(polygon: Vec<Vec2>, tolerance: f32) -> Vec<Vec2> {
+    let is_collinear = |prev: Vec2, point: Vec2, next: Vec2| {
+        (point - prev).perp_dot(next - point).abs() <= tolerance
+    };
+    let mut kept: Vec<Vec2> = Vec::with_capacity(polygon.len());
+    for point in polygon {
+        while kept.len() >= 2 && is_collinear(kept[kept.len() - 2], kept[kept.len() - 1], point) {
+            kept.pop();
+        }
+        kept.push(point);
+    }
+    while kept.len() > 2 {
+        let len = kept.len();
+        if is_collinear(kept[len - 2], kept[len - 1], kept[0]) {
+            kept.pop();
+        } else if is_collinear(kept[len - 1], kept[0], kept[1]) {
+            kept.remove(0);
+        } else {
+            break;
+        }
+    }
+    kept
+}
+
+/// Partitions a simple closed polygon into convex polygons with the Hertel-Mehlhorn algorithm.
+///
+/// The polygon is triangulated, then neighboring pieces are merged across their shared diagonal
+/// as long as the merged piece stays convex. The result has at most four times the minimal number of pieces.
+///
+/// # Returns
+///
+/// The convex pieces in counter-clockwise order, or `None` if the polygon can't be triangulated.
+pub fn convex_partition(polygon: &[Vec2]) -> Option<Vec<Vec<Vec2>>> {
+    let mut points = polygon.to_vec();
+    if signed_area(&points) < 0. {
+        points.reverse();
+    }
+    let mut pieces: Vec<Vec<u32>> = triangulate(&points)?.into_iter().map(Vec::from).collect();
+
+    let is_convex = |piece: &[u32]| {
+        (0..piece.len()).all(|i| {
+            let [a, b, c] = [i, i + 1, i + 2].map(|i| points[piece[i % piece.len()] as usize]);
+            orientation(a, b, c) >= 0.
+        })
+    };
+    // Returns the position of the directed edge `a -> b` in a piece.
+    let edge = |piece: &[u32], a: u32, b: u32| {
+        (0..piece.len()).find(|&i| piece[i] == a && piece[(i + 1) % piece.len()] == b)
+    };
+
+    let mut merged = true;
+    while merged {
+        merged = false;
+        'search: for first in 0..pieces.len() {
+            for i in 0..pieces[first].len() {
+                let (a, b) = (
+                    pieces[first][i],
+                    pieces[first][(i + 1) % pieces[first].len()],
+                );
+                for second in first + 1..pieces.len() {
+                    let Some(j) = edge(&pieces[second], b, a) else {
+                        continue;
+                    };
+                    let (outer, inner) = (&pieces[first], &pieces[second]);
+                    let piece: Vec<u32> = (1..=outer.len())
+                        .map(|offset| outer[(i + offset) % outer.len()])
+                        .chain((2..inner.len()).map(|offset| inner[(j + offset) % inner.len()]))
+                        .collect();
+                    if is_convex(&piece) {
+                        pieces[first] = piece;
+                        pieces.swap_remove(second);
+                        merged = true;
+                        break 'search;
+                    }
+                }
+            }
+        }
+    }
+
+    Some(
+        pieces
+            .into_iter()
+            .map(|piece| piece.into_iter().map(|i| points[i as usize]).collect())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(points: &[(f32, f32)]) -> Vec<Vec2> {
+        points.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    /// Returns the area of all triangles, asserting that each is counter-clockwise.
+    fn triangles_area(polygon: &[Vec2], triangles: &[[u32; 3]]) -> f32 {
+        triangles
+            .iter()
+            .map(|triangle| {
+                let area = signed_area(&triangle.map(|i| polygon[i as usize]));
+                assert!(area > 0., "{triangle:?} isn't counter-clockwise");
+                area
+            })
+            .sum()
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        let polygon = polygon(&[(0., 0.), (4., 0.), (4., 4.), (2., 1.), (0., 4.)]);
+        let triangles = triangulate(&polygon).unwrap();
+        assert_eq!(triangles.len(), polygon.len() - 2);
+        assert!((triangles_area(&polygon, &triangles) - signed_area(&polygon)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn triangulate_collinear_run() {
+        // The two points on the bottom edge are skipped, leaving a triangle for each of the other corners.
+        let polygon = polygon(&[(0., 0.), (1., 0.), (2., 0.), (3., 0.), (3., 3.), (0., 3.)]);
+        let triangles = triangulate(&polygon).unwrap();
+        assert_eq!(triangles.len(), polygon.len() - 2 - 2);
+        assert!((triangles_area(&polygon, &triangles) - signed_area(&polygon)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn triangulate_clockwise() {
+        let mut polygon = polygon(&[(0., 0.), (4., 0.), (4., 4.), (2., 1.), (0., 4.)]);
+        polygon.reverse();
+        let triangles = triangulate(&polygon).unwrap();
+        assert_eq!(triangles.len(), polygon.len() - 2);
+        assert!((triangles_area(&polygon, &triangles) + signed_area(&polygon)).abs() < 1e-5);
+    }
+
+    fn is_convex(polygon: &[Vec2]) -> bool {
+        let n = polygon.len();
+        (0..n).all(|i| orientation(polygon[i], polygon[(i + 1) % n], polygon[(i + 2) % n]) >= 0.)
+    }
+
+    #[test]
+    fn convex_partition_of_concave_polygon() {
+        let polygon = polygon(&[
+            (0., 0.),
+            (6., 0.),
+            (6., 4.),
+            (4., 4.),
+            (4., 2.),
+            (2., 2.),
+            (2., 4.),
+            (0., 4.),
+        ]);
+        let parts = convex_partition(&polygon).unwrap();
+        assert!(parts.len() > 1);
+        assert!(parts.iter().all(|part| is_convex(part)));
+        let area: f32 = parts.iter().map(|part| signed_area(part)).sum();
+        assert!((area - signed_area(&polygon)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn convex_partition_of_convex_polygon() {
+        let polygon = polygon(&[(0., 0.), (2., -1.), (4., 0.), (4., 3.), (0., 3.)]);
+        let parts = convex_partition(&polygon).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert!(is_convex(&parts[0]));
+        assert!((signed_area(&parts[0]) - signed_area(&polygon)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn simple_polygons() {
+        let square = polygon(&[(0., 0.), (2., 0.), (2., 2.), (0., 2.)]);
+        let bowtie = polygon(&[(0., 0.), (2., 2.), (2., 0.), (0., 2.)]);
+        let collinear = polygon(&[(0., 0.), (1., 0.), (2., 0.), (2., 2.), (0., 2.)]);
+        assert!(is_simple(&square));
+        assert!(is_simple(&collinear));
+        assert!(!is_simple(&bowtie));
+    }
+
+    #[test]
+    fn degenerate_polygons_are_not_simple() {
+        let duplicate = polygon(&[(0., 0.), (2., 0.), (2., 0.), (2., 2.), (0., 2.)]);
+        let closing_duplicate = polygon(&[(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let spike = polygon(&[(0., 0.), (2., 0.), (2., 2.), (2., 3.), (2., 1.), (0., 2.)]);
+        assert!(!is_simple(&duplicate));
+        assert!(!is_simple(&closing_duplicate));
+        assert!(!is_simple(&spike));
+    }
+
+    #[test]
+    fn chaikin_cuts_corners() {
+        let square = polygon(&[(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+        assert_eq!(chaikin(square.clone(), 0), square);
+        let smoothed = polygon(&[
+            (1., 0.),
+            (3., 0.),
+            (4., 1.),
+            (4., 3.),
+            (3., 4.),
+            (1., 4.),
+            (0., 3.),
+            (0., 1.),
+        ]);
+        assert_eq!(chaikin(square, 1), smoothed);
+    }
+
+    /// Returns `n` points on a circle of radius `10`, counter-clockwise.
+    fn circle(n: usize) -> Vec<Vec2> {
+        (0..n)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / n as f32) * 10.)
+            .collect()
+    }
+
+    #[test]
+    fn visvalingam_removes_collinear_points_first() {
+        let points = polygon(&[(0., 0.), (2., 0.), (4., 0.), (4.05, 2.), (4., 4.), (0., 4.)]);
+        let square = polygon(&[(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+        let without_collinear = polygon(&[(0., 0.), (4., 0.), (4.05, 2.), (4., 4.), (0., 4.)]);
+        assert_eq!(visvalingam(points.clone(), 5), without_collinear);
+        assert_eq!(visvalingam(points, 4), square);
+    }
+
+    #[test]
+    fn visvalingam_meets_the_budget_exactly() {
+        for budget in [3, 4, 10, 57, 99] {
+            assert_eq!(visvalingam(circle(100), budget).len(), budget);
+        }
+        assert_eq!(visvalingam(circle(100), 1).len(), 3);
+        assert_eq!(visvalingam(circle(10), 20), circle(10));
+    }
+
+    #[test]
+    fn weld_closes_near_duplicate_last_point() {
+        let square = polygon(&[(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+        let mut points = square.clone();
+        points.push(Vec2::new(0.01, 0.01));
+        assert_eq!(weld(points, 0.05), square);
+    }
+
+    #[test]
+    fn weld_merges_runs_into_their_first_point() {
+        let points = polygon(&[
+            (0., 0.),
+            (1., 0.),
+            (1.02, 0.),
+            (1.04, 0.),
+            (1., 1.),
+            (0., 1.),
+        ]);
+        assert_eq!(
+            weld(points, 0.05),
+            polygon(&[(0., 0.), (1., 0.), (1., 1.), (0., 1.)])
+        );
+    }
+
+    #[test]
+    fn douglas_peucker_meets_the_vertex_count_exactly() {
+        let original = circle(100);
+        for vertices in 3..100 {
+            let simplified = douglas_peucker_to(original.clone(), vertices);
+            assert_eq!(simplified.len(), vertices);
+            assert!(simplified.iter().all(|point| original.contains(point)));
+        }
+    }
+
+    #[test]
+    fn douglas_peucker_keeps_small_polygons() {
+        for vertices in [0, 1, 2] {
+            assert_eq!(douglas_peucker_to(circle(100), vertices).len(), 3);
+        }
+        assert_eq!(douglas_peucker_to(circle(100), 100), circle(100));
+        assert_eq!(douglas_peucker_to(circle(100), 150), circle(100));
+    }
+
+    #[test]
+    fn douglas_peucker_falls_back_to_visvalingam() {
+        // Every epsilon keeps either all four corners or only two of them.
+        let square = polygon(&[(0., 0.), (1., 0.), (1., 1.), (0., 1.)]);
+        assert_eq!(douglas_peucker(&square, 0.5).len(), 4);
+        assert_eq!(douglas_peucker(&square, 0.8).len(), 2);
+        assert_eq!(
+            douglas_peucker_to(square.clone(), 3),
+            visvalingam(square, 3)
+        );
+    }
+}
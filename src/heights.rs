@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use edges::{anchor::Anchor, binary_image::Bit, utils::center_of};
+use image::GenericImageView;
+
+/// Calculates the heights and scale of a heightfield based on the given points and anchor.
+///
+/// The points are sorted by their x-coordinate and only one point is kept for every x column:
+/// the first one in the order of the given polygon. This is why heightfields work best for images
+/// whose object spans the entire width of the image, or whose upper edge is traced first.
+///
+/// Between two consecutive columns, the height of the right column is repeated once per pixel,
+/// so the heightfield has one height per pixel of the object's width. Heights are measured from
+/// the anchor's vertical center (or the center of the points for `Anchor::AbsoluteCenter`)
+/// to the y-coordinate of the point, with y pointing up.
+///
+/// # Arguments
+///
+/// * `points` - The points of the polygon in image pixel coordinates.
+/// * `anchor` - The anchor used to determine the vertical origin of the heights.
+///
+/// # Returns
+///
+/// A tuple of the heights and the scale for the heightfield, where the scale's x is the number of heights.
+/// If `points` is empty, the heights are empty and the scale is `Vec2::ONE`.
+#[must_use]
+pub fn heights_and_scale(mut points: Vec<UVec2>, anchor: Anchor) -> (Vec<f32>, Vec2) {
+    // Sort points by their x-coordinate and remove duplicates based on x.
+    points.sort_by_cached_key(|p| p.x);
+    points.dedup_by(|p1, p2| p1.x == p2.x);
+
+    if let Some(dy) = anchor
+        .size()
+        .map(|size| size.y as f32 / 2.)
+        .or_else(|| center_of(&points).map(|center| center.y))
+    {
+        let heights: Vec<f32> = points
+            .windows(2)
+            .flat_map(|win| {
+                let (p1, p2) = (win[0], win[1]);
+                let y = dy - p2.y as f32;
+                (p1.x..p2.x).map(move |_| y)
+            })
+            .collect();
+
+        let scale = Vec2::new(heights.len() as f32, 1.);
+        (heights, scale)
+    } else {
+        (Vec::new(), Vec2::ONE)
+    }
+}
+
+/// Resamples heights to exactly `columns` evenly spaced heights, interpolating linearly between them.
+///
+/// The first and last heights are kept, so the resampled heightfield spans the same width
+/// when used with the same scale.
+///
+/// # Arguments
+///
+/// * `heights` - The heights to resample, such as the ones returned by [`heights_and_scale`].
+/// * `columns` - The number of heights to sample, at least two.
+///
+/// # Returns
+///
+/// The resampled heights, or an empty vector if `heights` is empty.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn resample_heights(heights: &[f32], columns: usize) -> Vec<f32> {
+    if heights.is_empty() {
+        return Vec::new();
+    }
+    let columns = columns.max(2);
+    let last = heights.len() - 1;
+    let step = last as f32 / (columns - 1) as f32;
+    (0..columns)
+        .map(|column| {
+            let position = column as f32 * step;
+            let index = (position as usize).min(last);
+            let next = (index + 1).min(last);
+            heights[index].lerp(heights[next], position - index as f32)
+        })
+        .collect()
+}
+
+/// Samples the height of the topmost opaque pixel in evenly spaced columns of a binary image.
+///
+/// This gives direct access to terrain heights for game logic such as procedural terrain painting,
+/// without creating colliders. Heights are measured in pixels from the bottom of the image to the top
+/// of the topmost opaque pixel, columns without opaque pixels have a height of `0`.
+///
+/// # Arguments
+///
+/// * `image` - The binary image, such as [`AbstractCollidersBuilder::image`](crate::prelude::AbstractCollidersBuilder::image).
+/// * `columns` - The number of columns to sample, at least two, spread from the first to the last pixel column.
+///
+/// # Returns
+///
+/// The heights from left to right, or an empty vector if the image is empty.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn image_to_heightfield_heights<I>(image: &I, columns: usize) -> Vec<f32>
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    let columns = columns.max(2);
+    let step = (width - 1) as f32 / (columns - 1) as f32;
+    (0..columns)
+        .map(|column| {
+            let x = ((column as f32 * step).round() as u32).min(width - 1);
+            (0..height)
+                .find(|&y| *image.get_pixel(x, y))
+                .map_or(0., |y| (height - y) as f32)
+        })
+        .collect()
+}
@@ -1,14 +1,34 @@
 #![doc = include_str!("../README.md")]
 
+/// The most commonly used items, `use bevy_collider_gen::prelude::*;` is enough for most use cases.
 pub mod prelude {
     pub extern crate edges;
     pub use crate::{
-        abstract_collider::{AbstractCollider, AbstractCollidersBuilder},
+        abstract_collider::{
+            AbstractCollider, AbstractCollidersBuilder, BackendCollider, CompoundColliderBuilder,
+            WindingOrder,
+        },
         collider_type::ColliderType,
+        components::ConnectedComponentsAlgo,
+        edges_builder::{ColorChannel, EdgesBuilder},
     };
-    pub use edges::anchor::Anchor;
+    pub use edges::{anchor::Anchor, Edges};
+
+    #[cfg(feature = "debug")]
+    pub use crate::debug::{ColliderDebugPlugin, DebugCollider};
 }
 
+pub mod heights;
+#[cfg(all(feature = "rapier2d", feature = "avian2d"))]
+pub mod migration;
+
 mod abstract_collider;
 mod collider_type;
+mod components;
+#[cfg(feature = "debug")]
+mod debug;
+mod edges_builder;
+mod geometry;
+#[cfg(feature = "svg")]
+mod svg;
 mod utils;
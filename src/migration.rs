@@ -0,0 +1,18 @@
+//! Conversions between the colliders of the supported physics backends, such as for migration tooling.
+
+use crate::abstract_collider::AbstractCollider;
+
+/// Converts a rapier2d collider into the equivalent avian2d collider.
+///
+/// The points of a polyline or convex polygon, as created from `convex_polyline` and `convex_hull`,
+/// are extracted with [`AbstractCollider::from_rapier`] and converted with [`AbstractCollider::to_avian`].
+///
+/// # Returns
+///
+/// The avian2d collider, or `None` for shapes without a direct equivalent, such as heightfields and triangle meshes.
+#[must_use]
+pub fn rapier2d_collider_to_avian2d_collider(
+    collider: &bevy_rapier2d::prelude::Collider,
+) -> Option<avian2d::prelude::Collider> {
+    AbstractCollider::from_rapier(collider).and_then(AbstractCollider::to_avian)
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use svgtypes::{SimplePathSegment, SimplifyingPathParser};
+
+/// The number of line segments a curve of a path is flattened into.
+const CURVE_SEGMENTS: u16 = 16;
+
+/// Parses SVG path data into closed polygons, one per subpath.
+///
+/// Arcs and smooth curves are converted into Bézier curves, which are flattened into line segments.
+#[allow(clippy::cast_possible_truncation)]
+pub fn polygons(path: &str) -> Result<Vec<Vec<Vec2>>, svgtypes::Error> {
+    let point = |x: f64, y: f64| Vec2::new(x as f32, y as f32);
+    let mut polygons: Vec<Vec<Vec2>> = Vec::new();
+    let mut current: Vec<Vec2> = Vec::new();
+    for segment in SimplifyingPathParser::from(path) {
+        let start = current.last().copied().unwrap_or_default();
+        match segment? {
+            SimplePathSegment::MoveTo { x, y } => {
+                polygons.push(std::mem::take(&mut current));
+                current.push(point(x, y));
+            }
+            SimplePathSegment::LineTo { x, y } => current.push(point(x, y)),
+            SimplePathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                let (first, second, end) = (point(x1, y1), point(x2, y2), point(x, y));
+                current.extend((1..=CURVE_SEGMENTS).map(|i| {
+                    let t = f32::from(i) / f32::from(CURVE_SEGMENTS);
+                    let (a, b, c) = (
+                        start.lerp(first, t),
+                        first.lerp(second, t),
+                        second.lerp(end, t),
+                    );
+                    a.lerp(b, t).lerp(b.lerp(c, t), t)
+                }));
+            }
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                let (control, end) = (point(x1, y1), point(x, y));
+                current.extend((1..=CURVE_SEGMENTS).map(|i| {
+                    let t = f32::from(i) / f32::from(CURVE_SEGMENTS);
+                    start.lerp(control, t).lerp(control.lerp(end, t), t)
+                }));
+            }
+            SimplePathSegment::ClosePath => polygons.push(std::mem::take(&mut current)),
+        }
+    }
+    polygons.push(current);
+    polygons.retain(|polygon| polygon.len() >= 3);
+    Ok(polygons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_polygon_per_subpath() {
+        let polygons = polygons("M 1 1 H 11 V 11 H 1 Z M 4 4 H 8 V 8 H 4 Z").unwrap();
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(
+            polygons[1],
+            [
+                Vec2::new(4., 4.),
+                Vec2::new(8., 4.),
+                Vec2::new(8., 8.),
+                Vec2::new(4., 8.)
+            ]
+        );
+    }
+
+    #[test]
+    fn curves_are_flattened() {
+        let polygons = polygons("M 0 0 Q 5 10 10 0 Z").unwrap();
+        assert_eq!(polygons[0].len(), 1 + usize::from(CURVE_SEGMENTS));
+        assert_eq!(polygons[0].last(), Some(&Vec2::new(10., 0.)));
+    }
+
+    #[test]
+    fn malformed_path() {
+        assert!(polygons("M 1 1 L").is_err());
+        assert!(polygons("M 1 1 X 2 2").is_err());
+    }
+}
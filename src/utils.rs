@@ -1,33 +1,65 @@
 use bevy::prelude::*;
-use edges::{anchor::Anchor, utils::center_of};
+use edges::binary_image::{BinaryImage, Bit};
+use image::GenericImageView;
 
-/// Calculates the heights and scale based on the given points and anchor.
-///
-/// This function processes a vector of 2D integer points, sorts them, removes duplicates based on the x-coordinate,
-/// and calculates the heights relative to a specified anchor. The heights are determined by the difference between
-/// the anchor's y position and the y-coordinates of the points.
-pub fn heights_and_scale(mut points: Vec<UVec2>, anchor: Anchor) -> (Vec<f32>, Vec2) {
-    // Sort points by their x-coordinate and remove duplicates based on x.
-    points.sort_by_cached_key(|p| p.x);
-    points.dedup_by(|p1, p2| p1.x == p2.x);
-
-    if let Some(dy) = anchor
-        .size()
-        .map(|size| size.y as f32 / 2.)
-        .or_else(|| center_of(&points).map(|center| center.y))
-    {
-        let heights: Vec<f32> = points
-            .windows(2)
-            .flat_map(|win| {
-                let (p1, p2) = (win[0], win[1]);
-                let y = dy - p2.y as f32;
-                (p1.x..p2.x).map(move |_| y)
-            })
-            .collect();
+use crate::geometry;
 
-        let scale = Vec2::new(heights.len() as f32, 1.);
-        (heights, scale)
+/// Creates a `BinaryImage` from one value per pixel in row-major order, where non-zero values are opaque.
+pub fn from_pixels(width: u32, height: u32, pixels: &[u8]) -> BinaryImage {
+    if pixels.is_empty() {
+        BinaryImage::new(width, height)
     } else {
-        (Vec::new(), Vec2::ONE)
+        BinaryImage::from_raw(width, height, pixels)
+    }
+}
+
+/// Rasterizes polygons into a binary image, where a pixel is opaque if its center lies inside
+/// the polygons according to the even-odd rule.
+pub fn rasterize(polygons: &[Vec<Vec2>], width: u32, height: u32) -> BinaryImage {
+    let pixels: Vec<u8> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| Vec2::new(x as f32 + 0.5, y as f32 + 0.5)))
+        .map(|center| {
+            let inside = polygons
+                .iter()
+                .filter(|polygon| geometry::contains_point(polygon, center))
+                .count();
+            u8::from(inside % 2 == 1)
+        })
+        .collect();
+    from_pixels(width, height, &pixels)
+}
+
+/// Copies a rectangular region of a binary image into a new `BinaryImage`.
+///
+/// The region must lie within the bounds of the image and must not be empty.
+pub fn crop<I>(image: &I, x: u32, y: u32, width: u32, height: u32) -> BinaryImage
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let pixels: Vec<u8> = image
+        .view(x, y, width, height)
+        .pixels()
+        .map(|(_, _, pixel)| u8::from(*pixel))
+        .collect();
+    from_pixels(width, height, &pixels)
+}
+
+/// Shrinks a binary image by an integer factor.
+///
+/// A pixel of the resulting image is opaque if any pixel of the corresponding block is opaque.
+pub fn downsample<I>(image: &I, factor: u32) -> BinaryImage
+where
+    I: GenericImageView<Pixel = Bit>,
+{
+    let (width, height) = (
+        image.width().div_ceil(factor),
+        image.height().div_ceil(factor),
+    );
+    let mut pixels = vec![0u8; width as usize * height as usize];
+    for (x, y, pixel) in image.pixels() {
+        if *pixel {
+            pixels[(y / factor) as usize * width as usize + (x / factor) as usize] = 1;
+        }
     }
+    from_pixels(width, height, &pixels)
 }
@@ -0,0 +1,16 @@
+//! Fails to compile if a type that is used in Bevy resources, components or tasks isn't `Send + Sync`.
+
+use bevy_collider_gen::prelude::{
+    edges::binary_image::BinaryImage, AbstractCollider, AbstractCollidersBuilder,
+    CompoundColliderBuilder, Edges, EdgesBuilder,
+};
+
+const fn assert_send_sync<T: Send + Sync>() {}
+
+const _: () = {
+    assert_send_sync::<AbstractCollider>();
+    assert_send_sync::<AbstractCollidersBuilder<BinaryImage>>();
+    assert_send_sync::<CompoundColliderBuilder>();
+    assert_send_sync::<EdgesBuilder>();
+    assert_send_sync::<Edges<BinaryImage>>();
+};